@@ -16,14 +16,47 @@ use std::{
     path::{Path, PathBuf}
 };
 
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use http::{
+    Method, StatusCode,
+    header::{CONTENT_LENGTH, CONTENT_TYPE},
+    request::Builder
+};
+use http_body_util::{BodyExt as _, LengthLimitError, Limited};
+use octocrab::Octocrab;
+use quick_xml::{Reader, XmlVersion, escape::resolve_predefined_entity, events::Event};
 use serde::Serialize;
+use tracing::debug;
 
 use crate::{
     config::TargetKind,
     error::{self, Error},
-    normalizer::{BadgeDescriptor, RenderTarget}
+    normalizer::{BadgeDescriptor, RenderTarget},
+    retry::{RetryConfig, retry_with_backoff},
+    warning::WarningCollector
 };
 
+/// Largest SVG badge accepted by [`validate_badge`], in bytes.
+const MAX_BADGE_BYTES: usize = 8 * 1024;
+/// Horizontal space available to the centered badge text, in pixels.
+const BADGE_TEXT_WIDTH: usize = 400;
+/// Font size of the `owner/repository` label line.
+const LABEL_FONT_SIZE: usize = 22;
+/// Font size of the display name line.
+const DISPLAY_FONT_SIZE: usize = 18;
+/// Largest embedded avatar data URI accepted by [`validate_badge`], in bytes.
+///
+/// Avatars are budgeted separately from [`MAX_BADGE_BYTES`].
+const MAX_AVATAR_BYTES: usize = 512 * 1024;
+/// Image types accepted for avatars, as `(extension, MIME type)` pairs.
+const AVATAR_TYPES: [(&str, &str); 5] = [
+    ("png", "image/png"),
+    ("jpg", "image/jpeg"),
+    ("gif", "image/gif"),
+    ("webp", "image/webp"),
+    ("svg", "image/svg+xml")
+];
+
 /// Result of generating badge assets for a render target.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BadgeAssets {
@@ -80,8 +113,422 @@ pub fn generate_badge_assets(
     })
 }
 
+/// Renders the badge for `target` in memory and checks that it is publishable
+/// without writing any artifacts.
+///
+/// The rendered SVG must be well-formed XML, stay below the badge size budget,
+/// and keep both text lines within the card width.
+///
+/// # Errors
+///
+/// Returns [`Error::SvgParse`](Error::SvgParse) when the SVG is not well-formed
+/// and [`Error::Validation`](Error::Validation) when the badge exceeds the size
+/// budget or a text line would overflow the card.
+///
+/// # Example
+///
+/// ```
+/// use imir::{parse_targets, validate_badge};
+///
+/// let yaml = r"
+/// targets:
+///   - owner: octocat
+///     repo: metrics
+///     type: open_source
+/// ";
+/// let document = parse_targets(yaml).expect("valid configuration");
+/// assert!(validate_badge(&document.targets[0]).is_ok());
+/// ```
+pub fn validate_badge(target: &RenderTarget) -> Result<(), Error> {
+    let avatar = avatar_href(target)?;
+    let svg = build_svg_content(target, avatar.as_deref());
+
+    check_well_formed(&svg)?;
+
+    let avatar_bytes = avatar.as_ref().map_or(0, String::len);
+    if avatar_bytes > MAX_AVATAR_BYTES {
+        return Err(Error::validation(format!(
+            "avatar is {avatar_bytes} bytes once embedded, exceeding the {MAX_AVATAR_BYTES} byte \
+             limit"
+        )));
+    }
+    let badge_bytes = svg.len() - avatar_bytes;
+    if badge_bytes > MAX_BADGE_BYTES {
+        return Err(Error::validation(format!(
+            "badge is {badge_bytes} bytes, exceeding the {MAX_BADGE_BYTES} byte limit"
+        )));
+    }
+
+    let lines = [
+        ("label", badge_label(target), LABEL_FONT_SIZE),
+        (
+            "display name",
+            Cow::Borrowed(target.display_name.as_str()),
+            DISPLAY_FONT_SIZE
+        )
+    ];
+    for (line, text, font_size) in lines {
+        if let Some((width, available)) = text_overflow(&text, font_size, avatar.is_some()) {
+            return Err(Error::validation(format!(
+                "{line} '{text}' is ~{width}px wide, exceeding {available}px"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Downloads remote profile avatars into `cache_dir` and points the targets at
+/// the cached files.
+///
+/// Badge rendering never touches the network: remote avatars are embedded
+/// only after this function rewrote them to local paths. Avatars already
+/// present in the cache are reused without a request.
+///
+/// # Arguments
+///
+/// * `octocrab` - HTTP client used to download avatars
+/// * `targets` - Targets whose remote avatars should be cached
+/// * `cache_dir` - Directory storing downloaded avatars
+/// * `retry_config` - Retry configuration for transient failures
+///
+/// # Returns
+///
+/// Number of avatars downloaded (cache misses).
+///
+/// # Errors
+///
+/// Returns [`Error::Service`](Error::Service) when a download fails or
+/// returns an unsupported image type and [`Error::BadgeIo`](Error::BadgeIo)
+/// when the cache cannot be written.
+pub async fn cache_remote_avatars(
+    octocrab: &Octocrab,
+    targets: &mut [RenderTarget],
+    cache_dir: &Path,
+    retry_config: &RetryConfig
+) -> Result<usize, Error> {
+    let mut downloaded = 0;
+
+    for target in targets.iter_mut() {
+        let Some(url) = target.avatar.as_deref().filter(|src| is_remote_avatar(src)) else {
+            continue;
+        };
+
+        let stem = format!("{:016x}", fnv1a(url.as_bytes()));
+        let cached = AVATAR_TYPES
+            .iter()
+            .map(|(extension, _)| cache_dir.join(format!("{stem}.{extension}")))
+            .find(|candidate| candidate.is_file());
+
+        let path = if let Some(path) = cached {
+            debug!("Using cached avatar {} for {}", path.display(), target.slug);
+            path
+        } else {
+            let operation = format!("download avatar {url}");
+            let (extension, bytes) =
+                retry_with_backoff(retry_config, &operation, || download_avatar(octocrab, url))
+                    .await
+                    .map_err(|e| {
+                        Error::service(
+                            e.message
+                                .as_deref()
+                                .map_or_else(|| e.to_string(), str::to_owned)
+                        )
+                    })?;
+
+            fs::create_dir_all(cache_dir)
+                .map_err(|source| error::badge_io_error(cache_dir, source))?;
+            let path = cache_dir.join(format!("{stem}.{extension}"));
+            fs::write(&path, bytes).map_err(|source| error::badge_io_error(&path, source))?;
+            downloaded += 1;
+            path
+        };
+
+        target.avatar = Some(path_to_string(&path));
+    }
+
+    Ok(downloaded)
+}
+
+async fn download_avatar(
+    octocrab: &Octocrab,
+    url: &str
+) -> Result<(&'static str, Vec<u8>), masterror::AppError> {
+    use masterror::AppError;
+
+    let request = octocrab
+        .build_request(Builder::new().method(Method::GET).uri(url), None::<&()>)
+        .map_err(|e| AppError::validation(format!("invalid avatar URL {url}: {e}")))?;
+    let response = octocrab
+        .execute(request)
+        .await
+        .map_err(|e| AppError::service(format!("request to {url} failed: {e}")))?;
+
+    if response.status() != StatusCode::OK {
+        return Err(AppError::service(format!(
+            "HTTP {} from {url}",
+            response.status().as_u16()
+        )));
+    }
+
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(str::trim)
+        .unwrap_or_default()
+        .to_owned();
+    let extension = AVATAR_TYPES
+        .iter()
+        .find(|(_, mime)| *mime == content_type)
+        .map(|(extension, _)| *extension)
+        .ok_or_else(|| {
+            AppError::service(format!(
+                "unsupported avatar content type '{content_type}' from {url}"
+            ))
+        })?;
+
+    let declared_length = response
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+    if declared_length.is_some_and(|length| length > MAX_AVATAR_BYTES) {
+        return Err(avatar_too_large(url));
+    }
+
+    let bytes = Limited::new(response.into_body(), MAX_AVATAR_BYTES)
+        .collect()
+        .await
+        .map_err(|e| {
+            if e.is::<LengthLimitError>() {
+                avatar_too_large(url)
+            } else {
+                AppError::service(format!("failed to read avatar from {url}: {e}"))
+            }
+        })?
+        .to_bytes();
+
+    Ok((extension, bytes.to_vec()))
+}
+
+fn avatar_too_large(url: &str) -> masterror::AppError {
+    masterror::AppError::validation(format!(
+        "avatar from {url} exceeds the {MAX_AVATAR_BYTES} byte limit"
+    ))
+}
+
+/// Rebases relative local avatar paths of `targets` onto `base_dir`.
+///
+/// Avatar paths in a targets configuration are relative to the configuration
+/// file rather than the working directory. Data URIs, remote URLs, and
+/// absolute paths are left untouched.
+///
+/// # Arguments
+///
+/// * `targets` - Targets whose avatars should be rebased
+/// * `base_dir` - Directory containing the targets configuration
+pub fn resolve_local_avatars(targets: &mut [RenderTarget], base_dir: &Path) {
+    for target in targets {
+        let Some(source) = target.avatar.as_deref() else {
+            continue;
+        };
+        if source.starts_with("data:") || is_remote_avatar(source) {
+            continue;
+        }
+        if Path::new(source).is_relative() {
+            target.avatar = Some(path_to_string(&base_dir.join(source)));
+        }
+    }
+}
+
+/// Records a warning for every target whose remote avatar will be left out
+/// of the rendered badge because it was not cached through
+/// [`cache_remote_avatars`].
+///
+/// # Arguments
+///
+/// * `targets` - Targets about to be rendered
+/// * `warnings` - Collector receiving one warning per skipped avatar
+pub fn warn_skipped_avatars<'a>(
+    targets: impl IntoIterator<Item = &'a RenderTarget>,
+    warnings: &mut WarningCollector
+) {
+    for target in targets {
+        if target.avatar.as_deref().is_some_and(is_remote_avatar) {
+            warnings.push(format!(
+                "skipping remote avatar for {}; fetch it with --fetch-avatars to embed it",
+                target.slug
+            ));
+        }
+    }
+}
+
+fn is_remote_avatar(source: &str) -> bool {
+    source.starts_with("https://") || source.starts_with("http://")
+}
+
+/// 64-bit FNV-1a hash used to derive stable avatar cache file names.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Resolves the avatar of `target` into an `href` that can be embedded into
+/// the badge.
+///
+/// Data URIs are used verbatim and local images are inlined as base64 data
+/// URIs. Remote URLs that were not cached through [`cache_remote_avatars`]
+/// are skipped; [`warn_skipped_avatars`] reports them.
+fn avatar_href(target: &RenderTarget) -> Result<Option<String>, Error> {
+    let Some(source) = target.avatar.as_deref() else {
+        return Ok(None);
+    };
+
+    if source.starts_with("data:") {
+        return Ok(Some(source.to_owned()));
+    }
+    if is_remote_avatar(source) {
+        return Ok(None);
+    }
+
+    let path = Path::new(source);
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| match extension.to_ascii_lowercase().as_str() {
+            "jpeg" => "jpg".to_owned(),
+            other => other.to_owned()
+        });
+    let mime = AVATAR_TYPES
+        .iter()
+        .find(|(known, _)| extension.as_deref() == Some(*known))
+        .map(|(_, mime)| *mime)
+        .ok_or_else(|| Error::validation(format!("unsupported avatar image type: {source}")))?;
+    let bytes = fs::read(path).map_err(|source| error::badge_io_error(path, source))?;
+
+    Ok(Some(format!(
+        "data:{mime};base64,{}",
+        STANDARD.encode(bytes)
+    )))
+}
+
+/// Returns the estimated label width and the available width when the
+/// `owner/repository` label of `target` overflows the badge card.
+pub(crate) fn label_overflow(target: &RenderTarget) -> Option<(usize, usize)> {
+    text_overflow(
+        &badge_label(target),
+        LABEL_FONT_SIZE,
+        target.avatar.is_some()
+    )
+}
+
+/// Returns the estimated width of `text` and the available width when the
+/// line would overflow the badge card.
+fn text_overflow(text: &str, font_size: usize, has_avatar: bool) -> Option<(usize, usize)> {
+    let (_, available) = text_layout(has_avatar);
+    let width = estimated_text_width(text, font_size);
+    (width > available).then_some((width, available))
+}
+
+/// Returns the horizontal center and available width of the text lines.
+///
+/// An avatar occupies the left side of the card and pushes the text right.
+const fn text_layout(has_avatar: bool) -> (usize, usize) {
+    if has_avatar {
+        (262, 316)
+    } else {
+        (220, BADGE_TEXT_WIDTH)
+    }
+}
+
+/// Approximates the rendered width of `text` assuming an average glyph width
+/// of 0.55em, which matches common sans-serif fonts closely enough for
+/// overflow checks.
+fn estimated_text_width(text: &str, font_size: usize) -> usize {
+    (text.chars().count() * font_size * 11).div_ceil(20)
+}
+
+/// Parses `document` with an XML reader and checks that it is well-formed: a
+/// single root element, matching tags, valid attributes and entity references.
+fn check_well_formed(document: &str) -> Result<(), Error> {
+    let parse_error = |position: u64, detail: String| Error::SvgParse {
+        message: format!("badge SVG is not well-formed at byte {position}: {detail}")
+    };
+
+    let mut reader = Reader::from_str(document);
+    let mut depth = 0_usize;
+    let mut roots = 0_usize;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| parse_error(reader.error_position(), e.to_string()))?;
+        let position = reader.buffer_position();
+
+        match event {
+            Event::Start(ref element) | Event::Empty(ref element) => {
+                if depth == 0 {
+                    roots += 1;
+                    if roots > 1 {
+                        return Err(parse_error(position, "multiple root elements".to_owned()));
+                    }
+                }
+                for attribute in element.attributes() {
+                    attribute
+                        .map_err(|e| parse_error(position, e.to_string()))?
+                        .normalized_value(XmlVersion::Implicit1_0)
+                        .map_err(|e| parse_error(position, e.to_string()))?;
+                }
+                if matches!(event, Event::Start(_)) {
+                    depth += 1;
+                }
+            }
+            Event::End(_) => depth -= 1,
+            Event::GeneralRef(reference) => {
+                let resolved = if reference.is_char_ref() {
+                    reference
+                        .resolve_char_ref()
+                        .map_err(|e| parse_error(position, e.to_string()))?
+                        .is_some()
+                } else {
+                    resolve_predefined_entity(&reference).is_some()
+                };
+                if !resolved {
+                    return Err(parse_error(
+                        position,
+                        format!("unknown entity reference &{};", &*reference)
+                    ));
+                }
+            }
+            Event::Text(text) if depth == 0 && !text.trim().is_empty() => {
+                return Err(parse_error(
+                    position,
+                    "text outside the root element".to_owned()
+                ));
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if depth > 0 {
+        return Err(parse_error(
+            reader.buffer_position(),
+            format!("{depth} element(s) never closed")
+        ));
+    }
+    if roots == 0 {
+        return Err(parse_error(0, "document has no root element".to_owned()));
+    }
+    Ok(())
+}
+
 fn write_svg(path: &Path, target: &RenderTarget) -> Result<(), Error> {
-    let contents = build_svg_content(target);
+    let avatar = avatar_href(target)?;
+    let contents = build_svg_content(target, avatar.as_deref());
     let file = File::create(path).map_err(|source| error::badge_io_error(path, source))?;
     let mut writer = BufWriter::new(file);
     writer
@@ -119,11 +566,19 @@ fn path_to_string(path: &Path) -> String {
     path.to_string_lossy().into_owned()
 }
 
-fn build_svg_content(target: &RenderTarget) -> String {
+fn build_svg_content(target: &RenderTarget, avatar: Option<&str>) -> String {
     use std::fmt::Write as _;
 
-    let mut buffer = String::with_capacity(256);
+    let mut buffer = String::with_capacity(256 + avatar.map_or(0, str::len));
+    let (text_x, _) = text_layout(avatar.is_some());
     let background = badge_background(target.kind);
+    let (primary, secondary) = target
+        .badge
+        .color
+        .as_deref()
+        .map_or((background.primary, background.secondary), |color| {
+            (color, color)
+        });
     let label = badge_label(target);
     let escaped_label = escape_xml(&label);
     let escaped_display = escape_xml(&target.display_name);
@@ -134,17 +589,36 @@ fn build_svg_content(target: &RenderTarget) -> String {
     );
     let _ = writeln!(
         buffer,
-        "  <defs>\n    <linearGradient id=\"imir-badge\" x1=\"0\" y1=\"0\" x2=\"1\" y2=\"1\">\n      <stop offset=\"0%\" stop-color=\"{}\" stop-opacity=\"0.92\"/>\n      <stop offset=\"100%\" stop-color=\"{}\" stop-opacity=\"1\"/>\n    </linearGradient>\n  </defs>",
-        background.primary, background.secondary,
+        "  <defs>\n    <linearGradient id=\"imir-badge\" x1=\"0\" y1=\"0\" x2=\"1\" y2=\"1\">\n      <stop offset=\"0%\" stop-color=\"{}\" stop-opacity=\"0.92\"/>\n      <stop offset=\"100%\" stop-color=\"{}\" stop-opacity=\"1\"/>\n    </linearGradient>",
+        primary, secondary,
     );
+    if avatar.is_some() {
+        buffer.push_str("    <clipPath id=\"imir-avatar\">\n      <circle cx=\"64\" cy=\"70\" r=\"36\"/>\n    </clipPath>\n");
+    }
+    buffer.push_str("  </defs>\n");
     buffer.push_str("  <rect x=\"8\" y=\"8\" width=\"424\" height=\"124\" rx=\"16\" fill=\"url(#imir-badge)\"/>");
+    if let Some(href) = avatar {
+        let _ = write!(
+            buffer,
+            "\n  <image x=\"28\" y=\"34\" width=\"72\" height=\"72\" href=\"{}\" clip-path=\"url(#imir-avatar)\" preserveAspectRatio=\"xMidYMid slice\"/>",
+            escape_xml(href)
+        );
+    }
+    if target.badge.show_icon {
+        let _ = write!(
+            buffer,
+            "\n  <g transform=\"translate(400 24)\" fill=\"#ffffff\" fill-opacity=\"0.85\">\n    <title>{}</title>\n    <path d=\"{}\"/>\n  </g>",
+            target.kind.display_label(),
+            target.kind.icon()
+        );
+    }
     let _ = writeln!(
         buffer,
-        "\n  <text x=\"220\" y=\"60\" text-anchor=\"middle\" font-family=\"'Segoe UI', 'SF Pro Display', sans-serif\" font-size=\"22\" fill=\"#ffffff\">{escaped_label}</text>",
+        "\n  <text x=\"{text_x}\" y=\"60\" text-anchor=\"middle\" font-family=\"'Segoe UI', 'SF Pro Display', sans-serif\" font-size=\"{LABEL_FONT_SIZE}\" fill=\"#ffffff\">{escaped_label}</text>",
     );
     let _ = writeln!(
         buffer,
-        "  <text x=\"220\" y=\"98\" text-anchor=\"middle\" font-family=\"'Segoe UI', 'SF Pro Display', sans-serif\" font-size=\"18\" fill=\"#f6f8fa\">{escaped_display}</text>",
+        "  <text x=\"{text_x}\" y=\"98\" text-anchor=\"middle\" font-family=\"'Segoe UI', 'SF Pro Display', sans-serif\" font-size=\"{DISPLAY_FONT_SIZE}\" fill=\"#f6f8fa\">{escaped_display}</text>",
     );
     buffer.push_str("</svg>\n");
 
@@ -248,13 +722,16 @@ mod tests {
             contributors_branch: "main".to_owned(),
             include_private: false,
             badge: BadgeDescriptor {
-                style:  BadgeStyle::Classic,
-                widget: BadgeWidgetDescriptor {
+                style:     BadgeStyle::Classic,
+                widget:    BadgeWidgetDescriptor {
                     columns:       2,
                     alignment:     BadgeWidgetAlignment::Center,
                     border_radius: 6
-                }
-            }
+                },
+                show_icon: false,
+                color:     None
+            },
+            avatar: None
         }
     }
 
@@ -313,7 +790,7 @@ mod tests {
         target.repository = None;
         target.owner = "Org > Team".to_owned();
 
-        let svg = build_svg_content(&target);
+        let svg = build_svg_content(&target, None);
         assert!(svg.contains("Org &gt; Team"));
         assert!(svg.contains("ACME &amp; &lt;Partners&gt;"));
     }
@@ -446,18 +923,283 @@ mod tests {
     #[test]
     fn svg_content_includes_gradient_definition() {
         let target = sample_target(TargetKind::PrivateProject);
-        let svg = build_svg_content(&target);
+        let svg = build_svg_content(&target, None);
         assert!(svg.contains("<linearGradient id=\"imir-badge\""));
         assert!(svg.contains("#0a3069"));
         assert!(svg.contains("#1b4b91"));
     }
 
+    #[test]
+    fn svg_content_omits_kind_icon_by_default() {
+        let target = sample_target(TargetKind::OpenSource);
+        let svg = build_svg_content(&target, None);
+        assert!(!svg.contains("<path"));
+        assert!(!svg.contains("<title>"));
+    }
+
+    #[test]
+    fn svg_content_draws_kind_icon_when_enabled() {
+        for kind in [
+            TargetKind::Profile,
+            TargetKind::OpenSource,
+            TargetKind::PrivateProject
+        ] {
+            let mut target = sample_target(kind);
+            target.badge.show_icon = true;
+
+            let svg = build_svg_content(&target, None);
+            assert!(svg.contains(&format!("<path d=\"{}\"/>", kind.icon())));
+            assert!(svg.contains(&format!("<title>{}</title>", kind.display_label())));
+            assert!(svg.find("<path").expect("icon path") > svg.find("<rect").expect("card"));
+        }
+    }
+
     #[test]
     fn svg_content_includes_text_elements() {
         let target = sample_target(TargetKind::OpenSource);
-        let svg = build_svg_content(&target);
+        let svg = build_svg_content(&target, None);
         assert!(svg.contains("<text"));
         assert!(svg.contains("octocat/example"));
         assert!(svg.contains("Example Dashboard"));
     }
+
+    #[test]
+    fn svg_content_uses_color_override_for_both_gradient_stops() {
+        let mut target = sample_target(TargetKind::OpenSource);
+        target.badge.color = Some("#0a3069".to_owned());
+        let svg = build_svg_content(&target, None);
+        assert_eq!(svg.matches("stop-color=\"#0a3069\"").count(), 2);
+        assert!(!svg.contains("#1f883d"));
+    }
+
+    #[test]
+    fn validate_badge_accepts_rendered_targets() {
+        for kind in [
+            TargetKind::Profile,
+            TargetKind::OpenSource,
+            TargetKind::PrivateProject
+        ] {
+            let mut target = sample_target(kind);
+            target.badge.show_icon = true;
+            target.display_name = "ACME & <Partners>".to_owned();
+            validate_badge(&target).expect("expected badge to validate");
+        }
+    }
+
+    #[test]
+    fn validate_badge_rejects_overflowing_label() {
+        let mut target = sample_target(TargetKind::OpenSource);
+        target.repository = Some("an-exceptionally-long-repository-name".to_owned());
+
+        match validate_badge(&target) {
+            Err(Error::Validation {
+                message
+            }) => {
+                assert!(
+                    message.starts_with("label 'octocat/an-exceptionally-long-repository-name'"),
+                    "unexpected message: {message}"
+                );
+            }
+            other => panic!("expected validation error, got {other:?}")
+        }
+    }
+
+    #[test]
+    fn check_well_formed_accepts_rendered_badge() {
+        let mut target = sample_target(TargetKind::Profile);
+        target.display_name = "ACME & <Partners>".to_owned();
+        let svg = build_svg_content(&target, None);
+        check_well_formed(&svg).expect("rendered badge is well-formed");
+    }
+
+    #[test]
+    fn check_well_formed_rejects_malformed_markup() {
+        for document in [
+            "<svg><text>ACME & Partners</text></svg>",
+            "<svg><text>a < b</text></svg>",
+            "<svg><text>&nbsp;</text></svg>",
+            "<svg><text>unclosed</svg>",
+            "<svg width=\"400></svg>",
+            "<svg><text>truncated</text>",
+            "<svg/><svg/>",
+            ""
+        ] {
+            match check_well_formed(document) {
+                Err(Error::SvgParse {
+                    message
+                }) => assert!(
+                    message.starts_with("badge SVG is not well-formed"),
+                    "unexpected message for {document:?}: {message}"
+                ),
+                other => panic!("expected parse error for {document:?}, got {other:?}")
+            }
+        }
+    }
+
+    #[test]
+    fn profile_badge_embeds_data_uri_avatar_as_circular_image() {
+        let mut target = sample_target(TargetKind::Profile);
+        target.repository = None;
+        target.avatar = Some("data:image/png;base64,iVBORw0KGgo=".to_owned());
+
+        let avatar = avatar_href(&target).expect("avatar resolves");
+        let svg = build_svg_content(&target, avatar.as_deref());
+
+        assert!(svg.contains(
+            "<image x=\"28\" y=\"34\" width=\"72\" height=\"72\" \
+             href=\"data:image/png;base64,iVBORw0KGgo=\" clip-path=\"url(#imir-avatar)\""
+        ));
+        assert!(svg.contains("<clipPath id=\"imir-avatar\">"));
+        assert!(svg.contains("<text x=\"262\" y=\"60\""));
+        validate_badge(&target).expect("avatar badge validates");
+    }
+
+    #[test]
+    fn local_avatar_is_inlined_as_data_uri() {
+        let directory = tempdir().expect("failed to create temp dir");
+        let avatar_path = directory.path().join("me.PNG");
+        fs::write(&avatar_path, b"png").expect("failed to write avatar");
+
+        let mut target = sample_target(TargetKind::Profile);
+        target.avatar = Some(path_to_string(&avatar_path));
+
+        assert_eq!(
+            avatar_href(&target).expect("avatar resolves").as_deref(),
+            Some("data:image/png;base64,cG5n")
+        );
+    }
+
+    #[test]
+    fn remote_avatar_is_skipped_until_cached() {
+        let mut target = sample_target(TargetKind::Profile);
+        target.avatar = Some("https://example.com/avatar.png".to_owned());
+
+        assert_eq!(avatar_href(&target).expect("avatar resolves"), None);
+        assert!(!build_svg_content(&target, None).contains("<image"));
+
+        let mut warnings = WarningCollector::new();
+        warn_skipped_avatars([&target], &mut warnings);
+        assert_eq!(
+            warnings.messages(),
+            ["skipping remote avatar for sample; fetch it with --fetch-avatars to embed it"]
+        );
+    }
+
+    #[test]
+    fn resolve_local_avatars_rebases_relative_paths_only() {
+        let sources = [
+            "avatars/me.png",
+            "/srv/me.png",
+            "https://example.com/me.png",
+            "data:image/png;base64,cG5n"
+        ];
+        let mut targets: Vec<RenderTarget> = sources
+            .iter()
+            .map(|source| {
+                let mut target = sample_target(TargetKind::Profile);
+                target.avatar = Some((*source).to_owned());
+                target
+            })
+            .collect();
+
+        resolve_local_avatars(&mut targets, Path::new("/etc/imir"));
+
+        let resolved: Vec<&str> = targets
+            .iter()
+            .filter_map(|target| target.avatar.as_deref())
+            .collect();
+        assert_eq!(
+            resolved,
+            [
+                "/etc/imir/avatars/me.png",
+                "/srv/me.png",
+                "https://example.com/me.png",
+                "data:image/png;base64,cG5n"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn cache_remote_avatars_downloads_once() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path}
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/avatar"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(b"gif".to_vec(), "image/gif"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let directory = tempdir().expect("failed to create temp dir");
+        let cache_dir = directory.path().join("avatars");
+        let octocrab = Octocrab::builder().build().expect("octocrab build");
+        let retry = RetryConfig {
+            max_attempts:     1,
+            initial_delay_ms: 0,
+            backoff_factor:   1.0
+        };
+        let url = format!("{}/avatar", server.uri());
+
+        for expected_downloads in [1, 0] {
+            let mut targets = vec![sample_target(TargetKind::Profile)];
+            targets[0].avatar = Some(url.clone());
+
+            let downloaded = cache_remote_avatars(&octocrab, &mut targets, &cache_dir, &retry)
+                .await
+                .expect("caching succeeds");
+
+            assert_eq!(downloaded, expected_downloads);
+            let cached = targets[0].avatar.as_deref().expect("avatar kept");
+            assert!(cached.ends_with(".gif"), "unexpected cache path {cached}");
+            assert_eq!(
+                avatar_href(&targets[0])
+                    .expect("avatar resolves")
+                    .as_deref(),
+                Some("data:image/gif;base64,Z2lm")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn cache_remote_avatars_rejects_oversized_downloads() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path}
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/avatar"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(vec![0_u8; MAX_AVATAR_BYTES + 1], "image/png")
+            )
+            .mount(&server)
+            .await;
+
+        let directory = tempdir().expect("failed to create temp dir");
+        let cache_dir = directory.path().join("avatars");
+        let octocrab = Octocrab::builder().build().expect("octocrab build");
+        let retry = RetryConfig {
+            max_attempts:     1,
+            initial_delay_ms: 0,
+            backoff_factor:   1.0
+        };
+        let mut targets = vec![sample_target(TargetKind::Profile)];
+        targets[0].avatar = Some(format!("{}/avatar", server.uri()));
+
+        let error = cache_remote_avatars(&octocrab, &mut targets, &cache_dir, &retry)
+            .await
+            .expect_err("oversized avatar must be rejected");
+
+        assert!(
+            error.to_string().contains("exceeds the 524288 byte limit"),
+            "unexpected error: {error}"
+        );
+        assert!(!cache_dir.exists());
+    }
 }
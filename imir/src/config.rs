@@ -11,7 +11,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::slug::SlugStrategy;
+use crate::{render::PrivacyPolicy, slug::SlugStrategy};
 
 /// Root configuration document describing all targets that should be rendered.
 ///
@@ -31,11 +31,62 @@ use crate::slug::SlugStrategy;
 /// ```
 #[derive(Debug, Deserialize, Serialize)]
 pub struct TargetConfig {
+    /// Optional document-wide defaults inherited by every entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub defaults: Option<TargetDefaults>,
+
+    /// Optional override of the profile plugin privacy mappings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub privacy: Option<PrivacyPolicy>,
+
     /// Collection of metrics targets to render.
     #[serde(default)]
     pub targets: Vec<TargetEntry>
 }
 
+impl TargetConfig {
+    /// Fills fields omitted by entries with the document defaults.
+    ///
+    /// Values set on an entry always take precedence over the defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use imir::TargetConfig;
+    ///
+    /// let yaml = r"
+    /// defaults:
+    ///   owner: ourorg
+    /// targets:
+    ///   - repo: hello-world
+    ///     type: open_source
+    /// ";
+    /// let mut config: TargetConfig = serde_yaml::from_str(yaml).expect("valid configuration");
+    /// config.apply_defaults();
+    /// assert_eq!(config.targets[0].owner.as_deref(), Some("ourorg"));
+    /// ```
+    pub fn apply_defaults(&mut self) {
+        let Some(defaults) = self.defaults.as_ref() else {
+            return;
+        };
+
+        for entry in &mut self.targets {
+            if entry.owner.is_none() {
+                entry.owner.clone_from(&defaults.owner);
+            }
+        }
+    }
+}
+
+/// Document-wide defaults applied to entries that omit the matching fields.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct TargetDefaults {
+    /// Optional GitHub account used for entries without an `owner`.
+    #[serde(default, alias = "user")]
+    pub owner: Option<String>
+}
+
 /// Raw configuration entry describing a single metrics target before
 /// normalization.
 ///
@@ -45,8 +96,10 @@ pub struct TargetConfig {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TargetEntry {
     /// GitHub account that owns the repository or profile.
-    #[serde(alias = "user")]
-    pub owner: String,
+    ///
+    /// May be omitted when the document provides `defaults.owner`.
+    #[serde(default, alias = "user", skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
 
     /// Optional repository name associated with the target.
     #[serde(default, alias = "repo")]
@@ -96,7 +149,15 @@ pub struct TargetEntry {
 
     /// Optional badge customization applied to the generated widget preview.
     #[serde(default)]
-    pub badge: Option<BadgeOptions>
+    pub badge: Option<BadgeOptions>,
+
+    /// Optional avatar embedded in profile badges.
+    ///
+    /// Accepts a data URI, a local image path, or an `http(s)` URL. Remote
+    /// avatars are embedded only after they have been fetched into the avatar
+    /// cache.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>
 }
 
 impl TargetEntry {
@@ -111,7 +172,7 @@ impl TargetEntry {
     /// use imir::{TargetEntry, TargetKind};
     ///
     /// let entry = TargetEntry {
-    ///     owner:               "octocat".to_owned(),
+    ///     owner:               Some("octocat".to_owned()),
     ///     repository:          Some("metrics".to_owned()),
     ///     target_type:         TargetKind::OpenSource,
     ///     slug:                None,
@@ -122,7 +183,8 @@ impl TargetEntry {
     ///     time_zone:           None,
     ///     display_name:        None,
     ///     include_private:     None,
-    ///     badge:               None
+    ///     badge:               None,
+    ///     avatar:              None
     /// };
     /// assert_eq!(entry.resolved_slug().as_deref(), Some("metrics"));
     /// ```
@@ -134,7 +196,7 @@ impl TargetEntry {
 
         match self.target_type {
             TargetKind::Profile => {
-                let derived = format!("{}-profile", self.owner);
+                let derived = format!("{}-profile", self.owner.as_deref()?);
                 SlugStrategy::builder(&derived).build()
             }
             TargetKind::OpenSource | TargetKind::PrivateProject => self
@@ -144,6 +206,15 @@ impl TargetEntry {
         }
     }
 
+    /// Returns the owner of this target, falling back to the document
+    /// defaults when the entry omits it.
+    #[must_use]
+    pub fn resolved_owner<'a>(&'a self, defaults: Option<&'a TargetDefaults>) -> Option<&'a str> {
+        self.owner
+            .as_deref()
+            .or_else(|| defaults.and_then(|defaults| defaults.owner.as_deref()))
+    }
+
     /// Provides the display name used for commit messages and logging.
     ///
     /// Leading and trailing whitespace is trimmed. When no override is
@@ -179,8 +250,10 @@ impl TargetEntry {
 /// use imir::{BadgeOptions, BadgeStyle};
 ///
 /// let options = BadgeOptions {
-///     style:  Some(BadgeStyle::FlatSquare),
-///     widget: None
+///     style:     Some(BadgeStyle::FlatSquare),
+///     widget:    None,
+///     show_icon: None,
+///     color:     None
 /// };
 /// assert_eq!(options.style, Some(BadgeStyle::FlatSquare));
 /// ```
@@ -193,7 +266,15 @@ pub struct BadgeOptions {
 
     /// Optional widget layout overrides.
     #[serde(default)]
-    pub widget: Option<BadgeWidgetOptions>
+    pub widget: Option<BadgeWidgetOptions>,
+
+    /// Optional flag that draws the target kind icon in the badge corner.
+    #[serde(default)]
+    pub show_icon: Option<bool>,
+
+    /// Optional `#rrggbb` background color replacing the kind gradient.
+    #[serde(default)]
+    pub color: Option<String>
 }
 
 /// Visual themes supported by the badge renderer.
@@ -275,14 +356,73 @@ where
     Ok(value)
 }
 
+/// Supported categories of metrics targets.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetKind {
+    /// Render a GitHub profile dashboard.
+    Profile,
+    /// Render an open-source repository dashboard.
+    OpenSource,
+    /// Render a private repository dashboard.
+    PrivateProject
+}
+
+impl TargetKind {
+    /// Returns the human-readable label used as the badge kind icon title.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use imir::TargetKind;
+    ///
+    /// assert_eq!(TargetKind::OpenSource.display_label(), "Open Source");
+    /// ```
+    #[must_use]
+    pub const fn display_label(self) -> &'static str {
+        match self {
+            Self::Profile => "Profile",
+            Self::OpenSource => "Open Source",
+            Self::PrivateProject => "Private"
+        }
+    }
+
+    /// Returns SVG path data for a 16x16 glyph representing the kind.
+    ///
+    /// The string is suitable for the `d` attribute of a `<path>` element:
+    /// a person for profiles, a fork for open-source repositories, and a
+    /// padlock for private projects.
+    #[must_use]
+    pub const fn icon(self) -> &'static str {
+        match self {
+            Self::Profile => {
+                "M8 8a3 3 0 1 0 0-6 3 3 0 0 0 0 6zM2 14.5C2 11.46 4.69 9.5 8 9.5s6 1.96 6 5V15H2z"
+            }
+            Self::OpenSource => {
+                "M5 3a2 2 0 1 1-4 0 2 2 0 0 1 4 0zm10 0a2 2 0 1 1-4 0 2 2 0 0 1 4 0zm-5 10a2 2 0 1 \
+                 1-4 0 2 2 0 0 1 4 0zM2.25 5h1.5v1.5c0 .41.34.75.75.75h7c.41 0 \
+                 .75-.34.75-.75V5h1.5v1.5a2.25 2.25 0 0 1-2.25 2.25H8.75V11h-1.5V8.75H4.5A2.25 \
+                 2.25 0 0 1 2.25 6.5z"
+            }
+            Self::PrivateProject => {
+                "M4 7V5a4 4 0 1 1 8 0v2h1a1 1 0 0 1 1 1v6a1 1 0 0 1-1 1H3a1 1 0 0 1-1-1V8a1 1 0 \
+                 0 1 1-1zm2 0h4V5a2 2 0 1 0-4 0z"
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{BadgeOptions, BadgeStyle, BadgeWidgetAlignment, TargetEntry, TargetKind};
+    use super::{
+        BadgeOptions, BadgeStyle, BadgeWidgetAlignment, TargetConfig, TargetDefaults, TargetEntry,
+        TargetKind
+    };
 
     #[test]
     fn resolved_slug_prefers_custom_value() {
         let entry = TargetEntry {
-            owner:               "octocat".to_owned(),
+            owner:               Some("octocat".to_owned()),
             repository:          Some("Hello-World".to_owned()),
             target_type:         TargetKind::OpenSource,
             slug:                Some("  Custom Slug  ".to_owned()),
@@ -293,7 +433,8 @@ mod tests {
             time_zone:           None,
             display_name:        None,
             include_private:     None,
-            badge:               None
+            badge:               None,
+            avatar:              None
         };
 
         let slug = entry
@@ -305,7 +446,7 @@ mod tests {
     #[test]
     fn resolved_slug_falls_back_to_profile_default() {
         let entry = TargetEntry {
-            owner:               "octocat".to_owned(),
+            owner:               Some("octocat".to_owned()),
             repository:          None,
             target_type:         TargetKind::Profile,
             slug:                None,
@@ -316,7 +457,8 @@ mod tests {
             time_zone:           None,
             display_name:        None,
             include_private:     None,
-            badge:               None
+            badge:               None,
+            avatar:              None
         };
 
         let slug = entry
@@ -328,7 +470,7 @@ mod tests {
     #[test]
     fn resolved_slug_falls_back_to_repository_name() {
         let entry = TargetEntry {
-            owner:               "octocat".to_owned(),
+            owner:               Some("octocat".to_owned()),
             repository:          Some("Example Repo".to_owned()),
             target_type:         TargetKind::PrivateProject,
             slug:                None,
@@ -339,7 +481,8 @@ mod tests {
             time_zone:           None,
             display_name:        None,
             include_private:     None,
-            badge:               None
+            badge:               None,
+            avatar:              None
         };
 
         let slug = entry
@@ -351,7 +494,7 @@ mod tests {
     #[test]
     fn resolved_slug_returns_none_when_unable_to_derive() {
         let entry = TargetEntry {
-            owner:               "octocat".to_owned(),
+            owner:               Some("octocat".to_owned()),
             repository:          Some("***".to_owned()),
             target_type:         TargetKind::OpenSource,
             slug:                None,
@@ -362,7 +505,8 @@ mod tests {
             time_zone:           None,
             display_name:        None,
             include_private:     None,
-            badge:               None
+            badge:               None,
+            avatar:              None
         };
 
         assert!(entry.resolved_slug().is_none());
@@ -371,7 +515,7 @@ mod tests {
     #[test]
     fn resolved_display_name_prefers_override() {
         let entry = TargetEntry {
-            owner:               "octocat".to_owned(),
+            owner:               Some("octocat".to_owned()),
             repository:          Some("repo".to_owned()),
             target_type:         TargetKind::OpenSource,
             slug:                None,
@@ -382,7 +526,8 @@ mod tests {
             time_zone:           None,
             display_name:        Some("  Friendly Name  ".to_owned()),
             include_private:     None,
-            badge:               None
+            badge:               None,
+            avatar:              None
         };
 
         let display = entry
@@ -394,7 +539,7 @@ mod tests {
     #[test]
     fn resolved_display_name_uses_repository_name() {
         let entry = TargetEntry {
-            owner:               "octocat".to_owned(),
+            owner:               Some("octocat".to_owned()),
             repository:          Some(" Repo With Spaces ".to_owned()),
             target_type:         TargetKind::OpenSource,
             slug:                None,
@@ -405,7 +550,8 @@ mod tests {
             time_zone:           None,
             display_name:        None,
             include_private:     None,
-            badge:               None
+            badge:               None,
+            avatar:              None
         };
 
         let display = entry
@@ -417,7 +563,7 @@ mod tests {
     #[test]
     fn resolved_display_name_returns_none_when_override_blank() {
         let entry = TargetEntry {
-            owner:               "octocat".to_owned(),
+            owner:               Some("octocat".to_owned()),
             repository:          None,
             target_type:         TargetKind::Profile,
             slug:                None,
@@ -428,12 +574,93 @@ mod tests {
             time_zone:           None,
             display_name:        Some("   ".to_owned()),
             include_private:     None,
-            badge:               None
+            badge:               None,
+            avatar:              None
         };
 
         assert!(entry.resolved_display_name().is_none());
     }
 
+    #[test]
+    fn resolved_slug_returns_none_for_profile_without_owner() {
+        let entry = TargetEntry {
+            owner:               None,
+            repository:          None,
+            target_type:         TargetKind::Profile,
+            slug:                None,
+            branch_name:         None,
+            contributors_branch: None,
+            target_path:         None,
+            temp_artifact:       None,
+            time_zone:           None,
+            display_name:        None,
+            include_private:     None,
+            badge:               None,
+            avatar:              None
+        };
+
+        assert!(entry.resolved_slug().is_none());
+    }
+
+    #[test]
+    fn resolved_owner_prefers_entry_over_defaults() {
+        let defaults = TargetDefaults {
+            owner: Some("ourorg".to_owned())
+        };
+        let mut entry = TargetEntry {
+            owner:               Some("octocat".to_owned()),
+            repository:          Some("repo".to_owned()),
+            target_type:         TargetKind::OpenSource,
+            slug:                None,
+            branch_name:         None,
+            contributors_branch: None,
+            target_path:         None,
+            temp_artifact:       None,
+            time_zone:           None,
+            display_name:        None,
+            include_private:     None,
+            badge:               None,
+            avatar:              None
+        };
+        assert_eq!(entry.resolved_owner(Some(&defaults)), Some("octocat"));
+
+        entry.owner = None;
+        assert_eq!(entry.resolved_owner(Some(&defaults)), Some("ourorg"));
+        assert_eq!(entry.resolved_owner(None), None);
+    }
+
+    #[test]
+    fn apply_defaults_fills_missing_owner_only() {
+        let yaml = r"
+            defaults:
+              owner: ourorg
+            targets:
+              - repo: inherited
+                type: open_source
+              - owner: octocat
+                repo: overridden
+                type: open_source
+        ";
+
+        let mut config: TargetConfig =
+            serde_yaml::from_str(yaml).expect("expected configuration to deserialize");
+        config.apply_defaults();
+
+        assert_eq!(config.targets[0].owner.as_deref(), Some("ourorg"));
+        assert_eq!(config.targets[1].owner.as_deref(), Some("octocat"));
+    }
+
+    #[test]
+    fn target_defaults_reject_unknown_fields() {
+        let yaml = r"
+            defaults:
+              branch: main
+            targets: []
+        ";
+
+        assert!(serde_yaml::from_str::<TargetConfig>(yaml).is_err());
+    }
+
     #[test]
     fn badge_options_supports_alignment_presets() {
         let yaml = r"
@@ -453,6 +680,63 @@ mod tests {
         assert_eq!(widget.border_radius, Some(12));
     }
 
+    #[test]
+    fn badge_options_parse_color_override() {
+        let options: BadgeOptions =
+            serde_yaml::from_str("color: '#ffcc00'").expect("expected badge options");
+        assert_eq!(options.color.as_deref(), Some("#ffcc00"));
+    }
+
+    #[test]
+    fn badge_options_parse_show_icon_flag() {
+        let options: BadgeOptions =
+            serde_yaml::from_str("show_icon: true").expect("expected badge options");
+        assert_eq!(options.show_icon, Some(true));
+    }
+
+    #[test]
+    fn target_kind_display_labels() {
+        assert_eq!(TargetKind::Profile.display_label(), "Profile");
+        assert_eq!(TargetKind::OpenSource.display_label(), "Open Source");
+        assert_eq!(TargetKind::PrivateProject.display_label(), "Private");
+    }
+
+    #[test]
+    fn target_kind_icons_are_distinct_path_data() {
+        let kinds = [
+            TargetKind::Profile,
+            TargetKind::OpenSource,
+            TargetKind::PrivateProject
+        ];
+
+        for kind in kinds {
+            let icon = kind.icon();
+            assert!(
+                icon.starts_with('M'),
+                "{kind:?} icon must start with moveto"
+            );
+            assert!(icon.ends_with('z'), "{kind:?} icon must close its path");
+            assert!(
+                !icon.contains("  "),
+                "{kind:?} icon must not contain indentation"
+            );
+            assert!(
+                !icon.contains(['<', '>', '"', '&']),
+                "{kind:?} icon must be embeddable in an attribute"
+            );
+        }
+
+        assert_ne!(TargetKind::Profile.icon(), TargetKind::OpenSource.icon());
+        assert_ne!(
+            TargetKind::OpenSource.icon(),
+            TargetKind::PrivateProject.icon()
+        );
+        assert_ne!(
+            TargetKind::Profile.icon(),
+            TargetKind::PrivateProject.icon()
+        );
+    }
+
     #[test]
     fn badge_widget_options_reject_invalid_columns() {
         let yaml = r"
@@ -484,15 +768,3 @@ mod tests {
         );
     }
 }
-
-/// Supported categories of metrics targets.
-#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
-#[serde(rename_all = "snake_case")]
-pub enum TargetKind {
-    /// Render a GitHub profile dashboard.
-    Profile,
-    /// Render an open-source repository dashboard.
-    OpenSource,
-    /// Render a private repository dashboard.
-    PrivateProject
-}
@@ -5,6 +5,9 @@
 ///
 /// Fetches and aggregates contributor statistics from GitHub API,
 /// providing last 30 days activity metrics per contributor.
+use std::{collections::HashMap, future::Future};
+
+use futures::stream::{self, StreamExt};
 use masterror::AppError;
 use octocrab::Octocrab;
 use serde::{Deserialize, Serialize};
@@ -48,6 +51,53 @@ pub struct ContributorActivity {
     pub is_bot:     bool
 }
 
+/// Weekly commit series of a contributor for last 30 days.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContributorWeeklyActivity {
+    pub login:          String,
+    /// Commit counts per week, ordered from oldest to newest.
+    pub weekly_commits: Vec<u32>
+}
+
+impl ContributorWeeklyActivity {
+    /// Returns the number of commits across all weeks in the series.
+    #[must_use]
+    pub fn total_commits(&self) -> u32 {
+        self.weekly_commits.iter().sum()
+    }
+}
+
+/// Number of repositories fetched concurrently by [`summarize_contributors`]
+/// unless the caller overrides it.
+///
+/// Kept low so org-wide summaries stay clear of GitHub secondary rate limits.
+pub const DEFAULT_SUMMARY_CONCURRENCY: usize = 4;
+
+/// Repository whose contributor statistics could not be fetched.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RepositoryFetchFailure {
+    /// Repository owner.
+    pub owner:      String,
+    /// Repository name.
+    pub repository: String,
+    /// Error reported while fetching the statistics.
+    pub error:      String
+}
+
+/// Contributor activity aggregated across several repositories.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContributorSummary {
+    /// Number of repositories whose statistics were fetched successfully.
+    pub repositories: usize,
+    /// Contributors merged by login, sorted by commits in descending order.
+    pub contributors: Vec<ContributorActivity>,
+    /// Repositories skipped because their statistics could not be fetched.
+    pub failures:     Vec<RepositoryFetchFailure>
+}
+
+/// Block glyphs used by [`format_sparkline`], ordered from lowest to highest.
+const SPARKLINE_GLYPHS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
 impl std::fmt::Display for ContributorActivity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -97,43 +147,8 @@ pub async fn fetch_contributor_activity(
     repo: &str,
     retry_config: &RetryConfig
 ) -> Result<Vec<ContributorActivity>, AppError> {
-    debug!("Fetching contributor stats for {}/{}", owner, repo);
-
-    let octocrab_clone = octocrab.clone();
-    let owner_str = owner.to_string();
-    let repo_str = repo.to_string();
-
-    let stats: Vec<ContributorStats> = retry_with_backoff(
-        retry_config,
-        &format!("contributor stats for {owner}/{repo}"),
-        || {
-            let octocrab = octocrab_clone.clone();
-            let owner = owner_str.clone();
-            let repo = repo_str.clone();
-            async move {
-                octocrab
-                    .get(
-                        format!("/repos/{owner}/{repo}/stats/contributors"),
-                        None::<&()>
-                    )
-                    .await
-                    .map_err(|e| {
-                        AppError::service(format!("failed to fetch contributor stats: {e}"))
-                    })
-            }
-        }
-    )
-    .await?;
-
-    let now = i64::try_from(
-        std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map_err(|e| AppError::internal(format!("system time error: {e}")))?
-            .as_secs()
-    )
-    .unwrap_or(i64::MAX);
-
-    let thirty_days_ago = now - (30 * 24 * 60 * 60);
+    let stats = fetch_contributor_stats(octocrab, owner, repo, retry_config).await?;
+    let thirty_days_ago = activity_window_start()?;
 
     let mut activities = Vec::with_capacity(stats.len());
 
@@ -166,7 +181,7 @@ pub async fn fetch_contributor_activity(
         });
     }
 
-    activities.sort_by_key(|a| std::cmp::Reverse(a.commits));
+    sort_activities(&mut activities);
 
     info!(
         "Found {} active contributors in last 30 days for {}/{}",
@@ -178,6 +193,267 @@ pub async fn fetch_contributor_activity(
     Ok(activities)
 }
 
+/// Fetches weekly commit series for the last 30 days from a GitHub repository.
+///
+/// Contributors without commits in the window are omitted. The result is
+/// sorted by total commits in descending order, ties broken by login.
+///
+/// # Arguments
+///
+/// * `octocrab` - Authenticated Octocrab client
+/// * `owner` - Repository owner
+/// * `repo` - Repository name
+/// * `retry_config` - Retry configuration for API calls
+///
+/// # Errors
+///
+/// Returns [`AppError`] when GitHub API requests fail.
+pub async fn fetch_contributor_weekly_activity(
+    octocrab: &Octocrab,
+    owner: &str,
+    repo: &str,
+    retry_config: &RetryConfig
+) -> Result<Vec<ContributorWeeklyActivity>, AppError> {
+    let stats = fetch_contributor_stats(octocrab, owner, repo, retry_config).await?;
+    let thirty_days_ago = activity_window_start()?;
+
+    let mut series = Vec::with_capacity(stats.len());
+
+    for stat in stats {
+        let mut recent_weeks: Vec<&WeeklyStats> = stat
+            .weeks
+            .iter()
+            .filter(|w| w.w >= thirty_days_ago)
+            .collect();
+        recent_weeks.sort_by_key(|w| w.w);
+
+        let activity = ContributorWeeklyActivity {
+            login:          stat.author.login,
+            weekly_commits: recent_weeks.iter().map(|w| w.c).collect()
+        };
+
+        if activity.total_commits() == 0 {
+            continue;
+        }
+
+        series.push(activity);
+    }
+
+    series.sort_by(|a, b| {
+        b.total_commits()
+            .cmp(&a.total_commits())
+            .then_with(|| a.login.cmp(&b.login))
+    });
+
+    Ok(series)
+}
+
+/// Renders a series of counts as a Unicode block sparkline.
+///
+/// Each count maps to one glyph scaled against the largest value in the
+/// series: zero renders as the lowest block, the maximum as the full block,
+/// and any non-zero count is guaranteed to render above the lowest block.
+///
+/// # Example
+///
+/// ```
+/// use imir::contributors::format_sparkline;
+///
+/// assert_eq!(format_sparkline(&[0, 1, 2, 4, 8]), "▁▂▃▅█");
+/// assert_eq!(format_sparkline(&[0, 0]), "▁▁");
+/// ```
+#[must_use]
+pub fn format_sparkline(counts: &[u32]) -> String {
+    let max = u64::from(counts.iter().copied().max().unwrap_or(0));
+    let top = SPARKLINE_GLYPHS.len() - 1;
+
+    counts
+        .iter()
+        .map(|&count| {
+            if max == 0 || count == 0 {
+                return SPARKLINE_GLYPHS[0];
+            }
+            let level = (u64::from(count) * top as u64).div_ceil(max);
+            SPARKLINE_GLYPHS[usize::try_from(level).map_or(top, |level| level.min(top))]
+        })
+        .collect()
+}
+
+/// Aggregates last-30-days contributor activity across many repositories.
+///
+/// At most `concurrency` repositories are fetched at once (values below one
+/// are treated as one). Repositories that fail after retries are recorded in
+/// [`ContributorSummary::failures`] instead of aborting the whole summary.
+///
+/// # Arguments
+///
+/// * `octocrab` - Authenticated Octocrab client
+/// * `repositories` - `(owner, repository)` pairs to summarize
+/// * `concurrency` - Maximum number of in-flight repository fetches
+/// * `retry_config` - Retry configuration for API calls
+///
+/// # Example
+///
+/// ```no_run
+/// use imir::{
+///     contributors::{DEFAULT_SUMMARY_CONCURRENCY, summarize_contributors},
+///     retry::RetryConfig
+/// };
+/// use octocrab::Octocrab;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let octocrab = Octocrab::builder().personal_token("token").build()?;
+/// let repositories = vec![("octocat".to_owned(), "hello-world".to_owned())];
+/// let summary = summarize_contributors(
+///     &octocrab,
+///     &repositories,
+///     DEFAULT_SUMMARY_CONCURRENCY,
+///     &RetryConfig::default()
+/// )
+/// .await;
+/// println!("{} contributors", summary.contributors.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn summarize_contributors(
+    octocrab: &Octocrab,
+    repositories: &[(String, String)],
+    concurrency: usize,
+    retry_config: &RetryConfig
+) -> ContributorSummary {
+    summarize_with(repositories, concurrency, |owner, repo| async move {
+        fetch_contributor_activity(octocrab, &owner, &repo, retry_config).await
+    })
+    .await
+}
+
+/// Drives the bounded fan-out behind [`summarize_contributors`] with an
+/// injectable fetcher.
+async fn summarize_with<F, Fut>(
+    repositories: &[(String, String)],
+    concurrency: usize,
+    fetch: F
+) -> ContributorSummary
+where
+    F: Fn(String, String) -> Fut,
+    Fut: Future<Output = Result<Vec<ContributorActivity>, AppError>>
+{
+    let results: Vec<_> = stream::iter(repositories.iter().cloned())
+        .map(|(owner, repo)| {
+            let pending = fetch(owner.clone(), repo.clone());
+            async move { (owner, repo, pending.await) }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut merged: HashMap<String, ContributorActivity> = HashMap::new();
+    let mut failures = Vec::new();
+    let mut succeeded = 0;
+
+    for (owner, repository, result) in results {
+        match result {
+            Ok(activities) => {
+                succeeded += 1;
+                for activity in activities {
+                    merged
+                        .entry(activity.login.clone())
+                        .and_modify(|existing| {
+                            existing.commits += activity.commits;
+                            existing.additions += activity.additions;
+                            existing.deletions += activity.deletions;
+                        })
+                        .or_insert(activity);
+                }
+            }
+            Err(error) => failures.push(RepositoryFetchFailure {
+                owner,
+                repository,
+                error: error.to_string()
+            })
+        }
+    }
+
+    failures.sort_by(|a, b| (&a.owner, &a.repository).cmp(&(&b.owner, &b.repository)));
+    let mut contributors: Vec<ContributorActivity> = merged.into_values().collect();
+    sort_activities(&mut contributors);
+
+    info!(
+        "Summarized {} contributors across {} repositories ({} failed)",
+        contributors.len(),
+        succeeded,
+        failures.len()
+    );
+
+    ContributorSummary {
+        repositories: succeeded,
+        contributors,
+        failures
+    }
+}
+
+/// Orders contributors by commits in descending order.
+///
+/// Ties are broken by login ascending, then additions descending, so equal
+/// commit counts never depend on the API response order.
+fn sort_activities(activities: &mut [ContributorActivity]) {
+    activities.sort_by(|a, b| {
+        b.commits
+            .cmp(&a.commits)
+            .then_with(|| a.login.cmp(&b.login))
+            .then_with(|| b.additions.cmp(&a.additions))
+    });
+}
+
+/// Fetches raw contributor statistics with retry.
+async fn fetch_contributor_stats(
+    octocrab: &Octocrab,
+    owner: &str,
+    repo: &str,
+    retry_config: &RetryConfig
+) -> Result<Vec<ContributorStats>, AppError> {
+    debug!("Fetching contributor stats for {}/{}", owner, repo);
+
+    let octocrab_clone = octocrab.clone();
+    let owner_str = owner.to_string();
+    let repo_str = repo.to_string();
+
+    retry_with_backoff(
+        retry_config,
+        &format!("contributor stats for {owner}/{repo}"),
+        || {
+            let octocrab = octocrab_clone.clone();
+            let owner = owner_str.clone();
+            let repo = repo_str.clone();
+            async move {
+                octocrab
+                    .get(
+                        format!("/repos/{owner}/{repo}/stats/contributors"),
+                        None::<&()>
+                    )
+                    .await
+                    .map_err(|e| {
+                        AppError::service(format!("failed to fetch contributor stats: {e}"))
+                    })
+            }
+        }
+    )
+    .await
+}
+
+/// Returns the Unix timestamp marking the start of the 30-day activity window.
+fn activity_window_start() -> Result<i64, AppError> {
+    let now = i64::try_from(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| AppError::internal(format!("system time error: {e}")))?
+            .as_secs()
+    )
+    .unwrap_or(i64::MAX);
+
+    Ok(now - (30 * 24 * 60 * 60))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -337,4 +613,157 @@ mod tests {
             .expect("fetch should succeed");
         assert!(activities.is_empty());
     }
+
+    #[test]
+    fn format_sparkline_maps_weekly_series_to_blocks() {
+        assert_eq!(format_sparkline(&[0, 1, 2, 4, 8]), "▁▂▃▅█");
+        assert_eq!(format_sparkline(&[3, 3, 3]), "███");
+        assert_eq!(format_sparkline(&[1, 100]), "▂█");
+    }
+
+    #[test]
+    fn format_sparkline_handles_empty_and_zero_series() {
+        assert_eq!(format_sparkline(&[]), "");
+        assert_eq!(format_sparkline(&[0, 0, 0]), "▁▁▁");
+    }
+
+    #[tokio::test]
+    async fn fetch_contributor_weekly_activity_orders_weeks_chronologically() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path}
+        };
+
+        let server = MockServer::start().await;
+        let now_secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("system time")
+            .as_secs();
+        let last_week = now_secs.saturating_sub(7 * 86400);
+        let two_weeks_ago = now_secs.saturating_sub(14 * 86400);
+        let stale_week = now_secs.saturating_sub(60 * 86400);
+        let body = format!(
+            r#"[
+                {{
+                    "author": {{ "login": "bob", "avatar_url": "https://example.com/b.png", "type": "User" }},
+                    "weeks": [
+                        {{ "w": {last_week}, "a": 1, "d": 1, "c": 1 }}
+                    ]
+                }},
+                {{
+                    "author": {{ "login": "alice", "avatar_url": "https://example.com/a.png", "type": "User" }},
+                    "weeks": [
+                        {{ "w": {stale_week}, "a": 9, "d": 9, "c": 9 }},
+                        {{ "w": {last_week}, "a": 10, "d": 2, "c": 4 }},
+                        {{ "w": {two_weeks_ago}, "a": 5, "d": 1, "c": 2 }}
+                    ]
+                }}
+            ]"#
+        );
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/cat/stats/contributors"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+            .mount(&server)
+            .await;
+
+        let octocrab = mock_octocrab(&server);
+        let series = fetch_contributor_weekly_activity(&octocrab, "octo", "cat", &fast_retry())
+            .await
+            .expect("fetch should succeed");
+
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0].login, "alice");
+        assert_eq!(series[0].weekly_commits, [2, 4]);
+        assert_eq!(series[0].total_commits(), 6);
+        assert_eq!(series[1].login, "bob");
+        assert_eq!(series[1].weekly_commits, [1]);
+    }
+
+    fn activity(login: &str, commits: u32) -> ContributorActivity {
+        ContributorActivity {
+            login: login.to_owned(),
+            avatar_url: format!("https://example.com/{login}.png"),
+            commits,
+            additions: commits * 10,
+            deletions: commits,
+            is_bot: false
+        }
+    }
+
+    #[tokio::test]
+    async fn summarize_with_bounds_in_flight_fetches() {
+        use std::{
+            sync::{
+                Arc,
+                atomic::{AtomicUsize, Ordering}
+            },
+            time::Duration
+        };
+
+        let repositories: Vec<(String, String)> = (0..10)
+            .map(|index| ("octo".to_owned(), format!("repo-{index}")))
+            .collect();
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let summary = summarize_with(&repositories, 3, |_, repo| {
+            let in_flight = Arc::clone(&in_flight);
+            let peak = Arc::clone(&peak);
+            async move {
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                if repo == "repo-4" {
+                    return Err(AppError::service("rate limited"));
+                }
+                Ok(vec![activity("alice", 1)])
+            }
+        })
+        .await;
+
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+        assert_eq!(summary.repositories, 9);
+        assert_eq!(summary.contributors.len(), 1);
+        assert_eq!(summary.contributors[0].commits, 9);
+        assert_eq!(summary.failures.len(), 1);
+        assert_eq!(summary.failures[0].repository, "repo-4");
+    }
+
+    #[tokio::test]
+    async fn summarize_with_merges_contributors_by_login() {
+        let repositories = vec![
+            ("octo".to_owned(), "cat".to_owned()),
+            ("octo".to_owned(), "dog".to_owned()),
+        ];
+
+        let summary = summarize_with(&repositories, 0, |_, repo| async move {
+            Ok(match repo.as_str() {
+                "cat" => vec![activity("alice", 2), activity("bob", 5)],
+                _ => vec![activity("alice", 4)]
+            })
+        })
+        .await;
+
+        assert_eq!(summary.repositories, 2);
+        assert!(summary.failures.is_empty());
+        let logins: Vec<(&str, u32)> = summary
+            .contributors
+            .iter()
+            .map(|c| (c.login.as_str(), c.commits))
+            .collect();
+        assert_eq!(logins, [("alice", 6), ("bob", 5)]);
+        assert_eq!(summary.contributors[0].additions, 60);
+    }
+
+    #[test]
+    fn sort_activities_breaks_commit_ties_by_login() {
+        let mut activities = vec![activity("zoe", 3), activity("adam", 3), activity("max", 7)];
+
+        sort_activities(&mut activities);
+
+        let logins: Vec<&str> = activities.iter().map(|a| a.login.as_str()).collect();
+        assert_eq!(logins, ["max", "adam", "zoe"]);
+    }
 }
@@ -5,8 +5,9 @@
 ///
 /// Scans repositories from stargazers and checks README files for badge
 /// presence and metrics links to identify repositories using IMIR.
-use std::collections::HashSet;
+use std::{collections::HashSet, time::Duration};
 
+use futures::{Stream, StreamExt, pin_mut, stream};
 use indicatif::{ProgressBar, ProgressStyle};
 use masterror::AppError;
 use octocrab::Octocrab;
@@ -52,6 +53,33 @@ impl std::fmt::Display for DiscoveredRepository {
     }
 }
 
+/// Outcome of a discovery run rendered as a single machine-parseable line.
+///
+/// The [`Display`](std::fmt::Display) form is a stable sequence of
+/// `key=value` pairs, e.g. `discovered=3 source=all elapsed_ms=1234`, meant
+/// for automation rather than humans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiscoverySummary<'a> {
+    /// Number of repositories discovered after deduplication.
+    pub discovered: usize,
+    /// Discovery source that was queried (`badge`, `stargazers` or `all`).
+    pub source:     &'a str,
+    /// Wall-clock duration of the discovery run.
+    pub elapsed:    Duration
+}
+
+impl std::fmt::Display for DiscoverySummary<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "discovered={} source={} elapsed_ms={}",
+            self.discovered,
+            self.source,
+            self.elapsed.as_millis()
+        )
+    }
+}
+
 /// Discovers repositories using IMIR badges via stargazers.
 ///
 /// This is an alias for [`discover_stargazer_repositories`] to maintain
@@ -168,6 +196,54 @@ pub async fn discover_stargazer_repositories(
     token: &str,
     config: &DiscoveryConfig
 ) -> Result<Vec<DiscoveredRepository>, AppError> {
+    let pages = stargazer_repository_pages(token, config)?;
+    pin_mut!(pages);
+
+    let mut discovered = Vec::with_capacity(500);
+    while let Some(page) = pages.next().await {
+        discovered.extend(page?);
+    }
+    Ok(discovered)
+}
+
+/// Streams repositories discovered from stargazers one stargazer page at a
+/// time.
+///
+/// Each item holds the badge repositories found on one page of stargazers,
+/// so consumers can act on results while later pages are still being
+/// fetched. The stream ends after `config.max_pages` pages, on the first
+/// empty page, or right after yielding an error.
+///
+/// # Arguments
+///
+/// * `token` - GitHub personal access token for API authentication
+/// * `config` - Discovery configuration (max pages to fetch)
+///
+/// # Errors
+///
+/// Returns [`AppError`] when the GitHub client cannot be initialized; API
+/// failures are yielded as stream items.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures::{StreamExt, pin_mut};
+/// use imir::{DiscoveryConfig, stargazer_repository_pages};
+///
+/// # async fn example() -> Result<(), masterror::AppError> {
+/// let token = std::env::var("GITHUB_TOKEN").unwrap();
+/// let pages = stargazer_repository_pages(&token, &DiscoveryConfig::default())?;
+/// pin_mut!(pages);
+/// while let Some(page) = pages.next().await {
+///     println!("Found {} repositories", page?.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn stargazer_repository_pages(
+    token: &str,
+    config: &DiscoveryConfig
+) -> Result<impl Stream<Item = Result<Vec<DiscoveredRepository>, AppError>> + use<>, AppError> {
     debug!("Initializing GitHub client for stargazer discovery");
     let octocrab = Octocrab::builder()
         .personal_token(token)
@@ -179,60 +255,101 @@ pub async fn discover_stargazer_repositories(
         IMIR_REPO_OWNER, IMIR_REPO_NAME
     );
 
-    let pb = stargazer_progress_bar();
-    let mut discovered = Vec::with_capacity(500);
-    let mut seen = HashSet::with_capacity(500);
-    let mut page = 1u32;
+    let state = StargazerPages {
+        octocrab,
+        config: config.clone(),
+        pb: stargazer_progress_bar(),
+        seen: HashSet::with_capacity(500),
+        page: 1,
+        found: 0,
+        done: false
+    };
+
+    Ok(stream::unfold(state, |mut state| async move {
+        if state.done {
+            return None;
+        }
+        match state.next_page().await {
+            Ok(Some(page)) => Some((Ok(page), state)),
+            Ok(None) => None,
+            Err(error) => {
+                state.done = true;
+                Some((Err(error), state))
+            }
+        }
+    }))
+}
+
+/// Pagination state behind [`stargazer_repository_pages`].
+struct StargazerPages {
+    octocrab: Octocrab,
+    config:   DiscoveryConfig,
+    pb:       ProgressBar,
+    seen:     HashSet<(String, String)>,
+    page:     u32,
+    found:    usize,
+    done:     bool
+}
 
-    loop {
-        pb.set_message(format!(
+impl StargazerPages {
+    /// Scans the next page of stargazers, or returns `None` once the last
+    /// page has been yielded.
+    async fn next_page(&mut self) -> Result<Option<Vec<DiscoveredRepository>>, AppError> {
+        if self.page > self.config.max_pages {
+            self.pb.finish_with_message(format!(
+                "Stargazer discovery complete: {} repositories found",
+                self.found
+            ));
+            info!(
+                "Stargazer discovery complete: {} repositories found",
+                self.found
+            );
+            return Ok(None);
+        }
+
+        let page = self.page;
+        self.pb.set_message(format!(
             "Fetching stargazers page {}/{}...",
-            page, config.max_pages
+            page, self.config.max_pages
         ));
         debug!("Fetching page {} of stargazers", page);
 
-        let stargazers = fetch_stargazers_page(&octocrab, page, &config.retry_config).await?;
+        let stargazers =
+            fetch_stargazers_page(&self.octocrab, page, &self.config.retry_config).await?;
         let items_count = stargazers.items.len();
         debug!("Processing {} stargazers on page {}", items_count, page);
 
+        let mut discovered = Vec::new();
         for (idx, stargazer) in stargazers.items.iter().enumerate() {
             let Some(user) = stargazer.user.as_ref() else {
                 continue;
             };
-            pb.set_message(format!(
+            self.pb.set_message(format!(
                 "Processing stargazer {}/{} on page {}...",
                 idx + 1,
                 items_count,
                 page
             ));
             collect_user_badge_repos(
-                &octocrab,
+                &self.octocrab,
                 &user.login,
-                config,
-                &pb,
+                &self.config,
+                &self.pb,
                 page,
-                &mut seen,
+                &mut self.seen,
                 &mut discovered
             )
             .await?;
         }
 
-        if items_count == 0 || page >= config.max_pages {
-            break;
-        }
-
-        page += 1;
+        self.found += discovered.len();
+        self.page = if items_count == 0 {
+            self.config.max_pages.saturating_add(1)
+        } else {
+            page + 1
+        };
+        Ok(Some(discovered))
     }
-
-    pb.finish_with_message(format!(
-        "Stargazer discovery complete: {} repositories found",
-        discovered.len()
-    ));
-    info!(
-        "Stargazer discovery complete: {} repositories found",
-        discovered.len()
-    );
-    Ok(discovered)
 }
 
 /// Builds the spinner-style [`ProgressBar`] used by stargazer discovery.
@@ -336,7 +453,7 @@ async fn collect_user_badge_repos(
             debug!("Found IMIR badge in repository: {}", repo_info);
             discovered.push(repo_info);
             pb.set_message(format!(
-                "Found {} repositories with badge (page {}/{})...",
+                "Found {} repositories with badge on page {}/{}...",
                 discovered.len(),
                 page,
                 config.max_pages
@@ -346,6 +463,171 @@ async fn collect_user_badge_repos(
     Ok(())
 }
 
+/// Selects up to `size` repositories uniformly at random from `repositories`.
+///
+/// Uses reservoir sampling, so the input is consumed in a single pass and
+/// every repository has the same chance of being picked regardless of its
+/// position. The selection is fully determined by `seed`; the returned
+/// repositories keep their original relative order.
+///
+/// # Example
+///
+/// ```
+/// use imir::{DiscoveredRepository, sample_repositories};
+///
+/// let repositories = (0..10).map(|index| DiscoveredRepository {
+///     owner:      "octocat".to_string(),
+///     repository: format!("repo-{index}")
+/// });
+/// let sample = sample_repositories(repositories, 3, 42);
+/// assert_eq!(sample.len(), 3);
+/// ```
+#[must_use]
+pub fn sample_repositories<I>(repositories: I, size: usize, seed: u64) -> Vec<DiscoveredRepository>
+where
+    I: IntoIterator<Item = DiscoveredRepository>
+{
+    let mut reservoir = Reservoir::new(size, seed);
+    for repository in repositories {
+        reservoir.offer(repository);
+    }
+    reservoir.into_sample()
+}
+
+/// Repositories sampled from a discovery stream.
+#[derive(Debug, Clone)]
+pub struct DiscoverySample {
+    /// Number of distinct repositories seen on the stream.
+    pub discovered:   usize,
+    /// Sampled repositories in discovery order.
+    pub repositories: Vec<DiscoveredRepository>
+}
+
+/// Samples up to `size` repositories from a stream of discovery pages as the
+/// pages arrive.
+///
+/// Only the reservoir is kept in memory, never the full discovery result.
+/// Repositories repeated across pages (for example when several sources are
+/// chained) are counted and sampled once. The selection is fully determined
+/// by `seed` and matches [`sample_repositories`] over the same sequence.
+///
+/// # Errors
+///
+/// Returns the first error yielded by `pages`.
+///
+/// # Example
+///
+/// ```
+/// use futures::stream;
+/// use imir::{DiscoveredRepository, sample_repository_pages};
+///
+/// # async fn example() -> Result<(), masterror::AppError> {
+/// let page = |range: std::ops::Range<usize>| {
+///     Ok(range
+///         .map(|index| DiscoveredRepository {
+///             owner:      "octocat".to_string(),
+///             repository: format!("repo-{index}")
+///         })
+///         .collect())
+/// };
+/// let pages = stream::iter([page(0..5), page(5..10)]);
+///
+/// let sample = sample_repository_pages(pages, 3, 42).await?;
+/// assert_eq!(sample.discovered, 10);
+/// assert_eq!(sample.repositories.len(), 3);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn sample_repository_pages<S>(
+    pages: S,
+    size: usize,
+    seed: u64
+) -> Result<DiscoverySample, AppError>
+where
+    S: Stream<Item = Result<Vec<DiscoveredRepository>, AppError>>
+{
+    pin_mut!(pages);
+
+    let mut reservoir = Reservoir::new(size, seed);
+    let mut seen = HashSet::new();
+    while let Some(page) = pages.next().await {
+        for repository in page? {
+            if seen.insert((repository.owner.clone(), repository.repository.clone())) {
+                reservoir.offer(repository);
+            }
+        }
+    }
+
+    Ok(DiscoverySample {
+        discovered:   reservoir.offered,
+        repositories: reservoir.into_sample()
+    })
+}
+
+/// Single-pass uniform sample (Algorithm R) of a fixed size.
+struct Reservoir {
+    size:    usize,
+    offered: usize,
+    rng:     SplitMix64,
+    entries: Vec<(usize, DiscoveredRepository)>
+}
+
+impl Reservoir {
+    fn new(size: usize, seed: u64) -> Self {
+        Self {
+            size,
+            offered: 0,
+            rng: SplitMix64(seed),
+            entries: Vec::with_capacity(size)
+        }
+    }
+
+    fn offer(&mut self, repository: DiscoveredRepository) {
+        let index = self.offered;
+        self.offered += 1;
+
+        if self.entries.len() < self.size {
+            self.entries.push((index, repository));
+            return;
+        }
+
+        let slot = self.rng.below(index as u64 + 1);
+        if let Some(entry) = usize::try_from(slot)
+            .ok()
+            .and_then(|slot| self.entries.get_mut(slot))
+        {
+            *entry = (index, repository);
+        }
+    }
+
+    /// Returns the sampled repositories in the order they were offered.
+    fn into_sample(mut self) -> Vec<DiscoveredRepository> {
+        self.entries.sort_by_key(|(index, _)| *index);
+        self.entries
+            .into_iter()
+            .map(|(_, repository)| repository)
+            .collect()
+    }
+}
+
+/// Small seedable PRNG (SplitMix64) used for reproducible sampling.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `0..bound` using Lemire's multiply-shift reduction.
+    fn below(&mut self, bound: u64) -> u64 {
+        ((u128::from(self.next()) * u128::from(bound)) >> 64) as u64
+    }
+}
+
 /// Extracts repository owner and name from README content.
 ///
 /// Searches for IMIR badge and metrics link pattern, extracting the repository
@@ -411,6 +693,121 @@ pub fn extract_repo_from_readme(readme_content: &str) -> Option<String> {
 mod tests {
     use super::*;
 
+    fn numbered_repositories(count: usize) -> Vec<DiscoveredRepository> {
+        (0..count)
+            .map(|index| DiscoveredRepository {
+                owner:      "octocat".to_string(),
+                repository: format!("repo-{index}")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sample_repositories_is_deterministic_for_a_seed() {
+        let names = |sample: Vec<DiscoveredRepository>| -> Vec<String> {
+            sample.into_iter().map(|repo| repo.repository).collect()
+        };
+
+        let first = names(sample_repositories(numbered_repositories(100), 5, 7));
+        let second = names(sample_repositories(numbered_repositories(100), 5, 7));
+
+        assert_eq!(first.len(), 5);
+        assert_eq!(first, second);
+        assert_ne!(
+            first,
+            ["repo-0", "repo-1", "repo-2", "repo-3", "repo-4"],
+            "sample should not simply be the first N results"
+        );
+        let indices: Vec<usize> = first
+            .iter()
+            .map(|name| name["repo-".len()..].parse().expect("numeric suffix"))
+            .collect();
+        assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn sample_repositories_returns_everything_when_input_is_small() {
+        let sample = sample_repositories(numbered_repositories(3), 5, 1);
+
+        assert_eq!(sample.len(), 3);
+        assert!(sample_repositories(numbered_repositories(3), 0, 1).is_empty());
+    }
+
+    #[test]
+    fn sample_repositories_covers_every_position() {
+        let mut seen = HashSet::new();
+        for seed in 0..200 {
+            for repo in sample_repositories(numbered_repositories(10), 2, seed) {
+                seen.insert(repo.repository);
+            }
+        }
+
+        assert_eq!(seen.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn sample_repository_pages_samples_mocked_stream_deterministically() {
+        let pages = || {
+            let repositories = numbered_repositories(30);
+            let mut batches: Vec<Result<Vec<DiscoveredRepository>, AppError>> = repositories
+                .chunks(10)
+                .map(|chunk| Ok(chunk.to_vec()))
+                .collect();
+            batches.push(Ok(numbered_repositories(3)));
+            stream::iter(batches)
+        };
+
+        let first = sample_repository_pages(pages(), 4, 11)
+            .await
+            .expect("sampling succeeds");
+        let second = sample_repository_pages(pages(), 4, 11)
+            .await
+            .expect("sampling succeeds");
+        let names = |repositories: Vec<DiscoveredRepository>| -> Vec<String> {
+            repositories
+                .into_iter()
+                .map(|repo| repo.repository)
+                .collect()
+        };
+
+        assert_eq!(first.discovered, 30);
+        assert_eq!(first.repositories.len(), 4);
+        let first = names(first.repositories);
+        assert_eq!(first, names(second.repositories));
+        assert_eq!(
+            first,
+            names(sample_repositories(numbered_repositories(30), 4, 11))
+        );
+    }
+
+    #[tokio::test]
+    async fn sample_repository_pages_stops_at_first_error() {
+        let pages = stream::iter([
+            Ok(numbered_repositories(2)),
+            Err(AppError::service("stargazers unavailable")),
+            Ok(numbered_repositories(5))
+        ]);
+
+        let error = sample_repository_pages(pages, 3, 1)
+            .await
+            .expect_err("error is propagated");
+        assert_eq!(error.message.as_deref(), Some("stargazers unavailable"));
+    }
+
+    #[test]
+    fn discovery_summary_formats_key_value_pairs() {
+        let summary = DiscoverySummary {
+            discovered: 3,
+            source:     "all",
+            elapsed:    Duration::from_micros(1_234_567)
+        };
+
+        assert_eq!(
+            summary.to_string(),
+            "discovered=3 source=all elapsed_ms=1234"
+        );
+    }
+
     #[test]
     fn extract_repo_from_readme_finds_valid_pattern() {
         let readme = r"
@@ -4,10 +4,51 @@
 /// GitHub CLI operations for PR creation.
 ///
 /// Provides utilities for creating PRs with label handling.
-use std::process::Command;
+use std::{collections::HashSet, process::Command};
 
 use masterror::AppError;
+use octocrab::Octocrab;
 use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+/// Program invoked for GitHub CLI operations.
+const GH_PROGRAM: &str = "gh";
+/// Color assigned to labels created for PRs.
+const DEFAULT_LABEL_COLOR: &str = "ededed";
+/// Description assigned to labels created for PRs.
+const DEFAULT_LABEL_DESCRIPTION: &str = "Infrastructure automation";
+
+/// Repository label definition used by [`ensure_labels`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Label {
+    /// Label name; matched case-insensitively against existing labels.
+    pub name:        String,
+    /// Hex color without the leading `#`.
+    pub color:       String,
+    /// Label description.
+    pub description: String
+}
+
+impl Label {
+    /// Creates a label with the default color and description used for PRs.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name:        name.into(),
+            color:       DEFAULT_LABEL_COLOR.to_owned(),
+            description: DEFAULT_LABEL_DESCRIPTION.to_owned()
+        }
+    }
+}
+
+/// Outcome of [`ensure_labels`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnsureReport {
+    /// Labels that were missing and have been created.
+    pub created:  Vec<String>,
+    /// Labels that already existed in the repository.
+    pub existing: Vec<String>
+}
 
 /// Result of PR creation operation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +63,23 @@ pub struct PrCreateResult {
     pub message:   String
 }
 
+/// Result of probing PR state without mutating the repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrProbeResult {
+    /// Always `false`: probing never creates a PR.
+    pub created:         bool,
+    /// Whether running the create flow would open a new PR.
+    pub would_create:    bool,
+    /// Number of the already open PR, if any.
+    pub pr_number:       Option<u64>,
+    /// Requested labels that already exist in the repository.
+    pub existing_labels: Vec<String>,
+    /// Requested labels the create flow would have to create.
+    pub missing_labels:  Vec<String>,
+    /// Message describing the result.
+    pub message:         String
+}
+
 /// Creates a PR idempotently with label handling.
 ///
 /// # Arguments
@@ -40,14 +98,14 @@ pub struct PrCreateResult {
 ///
 /// # Errors
 ///
-/// Returns [`AppError`] when gh commands fail.
+/// Returns [`AppError`] when gh commands fail or labels cannot be ensured.
 ///
 /// # Example
 ///
 /// ```no_run
 /// use imir::gh_pr_create;
 ///
-/// # fn example() -> Result<(), masterror::AppError> {
+/// # async fn example() -> Result<(), masterror::AppError> {
 /// let result = gh_pr_create(
 ///     "owner/repo",
 ///     "feature-branch",
@@ -56,14 +114,15 @@ pub struct PrCreateResult {
 ///     "Auto-generated metrics update",
 ///     &["ci", "metrics"],
 ///     "ghp_token"
-/// )?;
+/// )
+/// .await?;
 /// if result.created {
 ///     println!("Created PR: {:?}", result.pr_url);
 /// }
 /// # Ok(())
 /// # }
 /// ```
-pub fn gh_pr_create(
+pub async fn gh_pr_create(
     repo: &str,
     head: &str,
     base: &str,
@@ -72,128 +131,354 @@ pub fn gh_pr_create(
     labels: &[&str],
     gh_token: &str
 ) -> Result<PrCreateResult, AppError> {
-    let existing_pr = check_existing_pr(repo, head, gh_token)?;
+    let request = PrRequest {
+        repo,
+        head,
+        base,
+        title,
+        body,
+        labels
+    };
 
-    if let Some(pr_number) = existing_pr {
-        return Ok(PrCreateResult {
-            created:   false,
-            pr_number: Some(pr_number),
-            pr_url:    None,
-            message:   format!("PR #{pr_number} already open for {repo}:{head} -> {base}")
-        });
+    pr_create(
+        &GhCli::new(GH_PROGRAM, gh_token),
+        &github_client(gh_token)?,
+        &request
+    )
+    .await
+}
+
+/// Ensures every label in `labels` exists in `repo`.
+///
+/// Existing labels are listed once; only the missing ones are created.
+/// Names are compared case-insensitively, as GitHub does.
+///
+/// # Arguments
+///
+/// * `octocrab` - GitHub client used for the labels API
+/// * `repo` - Repository in owner/repo format
+/// * `labels` - Labels that must exist
+///
+/// # Returns
+///
+/// [`EnsureReport`] listing which labels were created and which existed.
+///
+/// # Errors
+///
+/// Returns [`AppError`] when `repo` is malformed, labels cannot be listed,
+/// or a missing label cannot be created.
+///
+/// # Example
+///
+/// ```no_run
+/// use imir::{Label, ensure_labels};
+/// use octocrab::Octocrab;
+///
+/// # async fn example() -> Result<(), masterror::AppError> {
+/// let octocrab = Octocrab::builder()
+///     .personal_token("ghp_token")
+///     .build()
+///     .unwrap();
+/// let report = ensure_labels(&octocrab, "owner/repo", &[Label::new("metrics")]).await?;
+/// println!("Created labels: {:?}", report.created);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn ensure_labels(
+    octocrab: &Octocrab,
+    repo: &str,
+    labels: &[Label]
+) -> Result<EnsureReport, AppError> {
+    let (owner, name) = split_repository(repo)?;
+    let known = list_label_names(octocrab, repo).await?;
+    let issues = octocrab.issues(owner, name);
+
+    let mut report = EnsureReport::default();
+    for label in labels {
+        if known.contains(&label.name.to_lowercase()) {
+            report.existing.push(label.name.clone());
+            continue;
+        }
+
+        debug!("Creating label '{}' in {repo}", label.name);
+        issues
+            .create_label(&label.name, &label.color, &label.description)
+            .await
+            .map_err(|e| {
+                AppError::service(format!(
+                    "failed to create label '{}' in {repo}: {e}",
+                    label.name
+                ))
+            })?;
+        report.created.push(label.name.clone());
     }
 
-    ensure_labels(repo, labels, gh_token)?;
+    Ok(report)
+}
+
+/// Reports whether [`gh_pr_create`] would open a PR without mutating anything.
+///
+/// Only the existing-PR lookup and the label listing are executed; neither
+/// labels nor PRs are created. Labels are matched case-insensitively, as in
+/// [`ensure_labels`].
+///
+/// # Arguments
+///
+/// * `repo` - Repository in owner/repo format
+/// * `head` - Head branch name
+/// * `base` - Base branch name
+/// * `labels` - Labels the create flow would add
+/// * `gh_token` - GitHub token for authentication
+///
+/// # Returns
+///
+/// [`PrProbeResult`] describing the existing PR and label state.
+///
+/// # Errors
+///
+/// Returns [`AppError`] when gh commands cannot be executed or labels cannot
+/// be listed.
+///
+/// # Example
+///
+/// ```no_run
+/// use imir::gh_pr_probe;
+///
+/// # async fn example() -> Result<(), masterror::AppError> {
+/// let report = gh_pr_probe(
+///     "owner/repo",
+///     "feature-branch",
+///     "main",
+///     &["ci", "metrics"],
+///     "ghp_token"
+/// )
+/// .await?;
+/// if report.would_create {
+///     println!("Missing labels: {:?}", report.missing_labels);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn gh_pr_probe(
+    repo: &str,
+    head: &str,
+    base: &str,
+    labels: &[&str],
+    gh_token: &str
+) -> Result<PrProbeResult, AppError> {
+    pr_probe(
+        &GhCli::new(GH_PROGRAM, gh_token),
+        &github_client(gh_token)?,
+        repo,
+        head,
+        base,
+        labels
+    )
+    .await
+}
 
-    let pr_url = create_pr(repo, head, base, title, body, labels, gh_token)?;
+/// Pull request opened by the create flow.
+struct PrRequest<'a> {
+    repo:   &'a str,
+    head:   &'a str,
+    base:   &'a str,
+    title:  &'a str,
+    body:   &'a str,
+    labels: &'a [&'a str]
+}
 
-    Ok(PrCreateResult {
-        created:   true,
-        pr_number: None,
-        pr_url:    Some(pr_url.clone()),
-        message:   format!("Created PR: {pr_url}")
-    })
+/// `gh` executable together with the token it authenticates with.
+struct GhCli<'a> {
+    program: &'a str,
+    token:   &'a str
 }
 
-fn check_existing_pr(repo: &str, head: &str, gh_token: &str) -> Result<Option<u64>, AppError> {
-    let output = Command::new("gh")
-        .env("GH_TOKEN", gh_token)
-        .args([
+impl<'a> GhCli<'a> {
+    const fn new(program: &'a str, token: &'a str) -> Self {
+        Self {
+            program,
+            token
+        }
+    }
+
+    fn command(&self) -> Command {
+        let mut command = Command::new(self.program);
+        command.env("GH_TOKEN", self.token);
+        command
+    }
+
+    fn existing_pr(&self, repo: &str, head: &str) -> Result<Option<u64>, AppError> {
+        let output = self
+            .command()
+            .args([
+                "pr",
+                "list",
+                "-R",
+                repo,
+                "--head",
+                head,
+                "--state",
+                "open",
+                "--json",
+                "number",
+                "--jq",
+                ".[0].number"
+            ])
+            .output()
+            .map_err(|e| AppError::service(format!("gh pr list failed: {e}")))?;
+
+        if !output.status.success() {
+            return Ok(None);
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if stdout.is_empty() || stdout == "null" {
+            return Ok(None);
+        }
+
+        let pr_number = stdout
+            .parse::<u64>()
+            .map_err(|e| AppError::validation(format!("invalid PR number: {e}")))?;
+
+        Ok(Some(pr_number))
+    }
+
+    fn create_pr(&self, request: &PrRequest<'_>) -> Result<String, AppError> {
+        let mut args = vec![
             "pr",
-            "list",
+            "create",
             "-R",
-            repo,
+            request.repo,
             "--head",
-            head,
-            "--state",
-            "open",
-            "--json",
-            "number",
-            "--jq",
-            ".[0].number"
-        ])
-        .output()
-        .map_err(|e| AppError::service(format!("gh pr list failed: {e}")))?;
+            request.head,
+            "--base",
+            request.base,
+            "--title",
+            request.title,
+            "--body",
+            request.body,
+        ];
+        for label in request.labels {
+            args.extend(["--label", label]);
+        }
 
-    if !output.status.success() {
-        return Ok(None);
-    }
+        let output = self
+            .command()
+            .args(&args)
+            .output()
+            .map_err(|e| AppError::service(format!("gh pr create failed: {e}")))?;
 
-    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    if stdout.is_empty() || stdout == "null" {
-        return Ok(None);
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(AppError::service(format!("gh pr create failed: {stderr}")));
+        }
+
+        let pr_url = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+        Ok(pr_url)
     }
+}
 
-    let pr_number = stdout
-        .parse::<u64>()
-        .map_err(|e| AppError::validation(format!("invalid PR number: {e}")))?;
+fn github_client(gh_token: &str) -> Result<Octocrab, AppError> {
+    Octocrab::builder()
+        .personal_token(gh_token)
+        .build()
+        .map_err(|e| AppError::service(format!("failed to initialize GitHub client: {e}")))
+}
 
-    Ok(Some(pr_number))
+fn split_repository(repo: &str) -> Result<(&str, &str), AppError> {
+    repo.split_once('/')
+        .filter(|(owner, name)| !owner.is_empty() && !name.is_empty())
+        .ok_or_else(|| AppError::validation(format!("invalid repository '{repo}'")))
 }
 
-fn ensure_labels(repo: &str, labels: &[&str], gh_token: &str) -> Result<(), AppError> {
-    for label in labels {
-        let view_output = Command::new("gh")
-            .env("GH_TOKEN", gh_token)
-            .args(["label", "view", label, "-R", repo])
-            .output()
-            .map_err(|e| AppError::service(format!("gh label view failed: {e}")))?;
-
-        if !view_output.status.success() {
-            let _ = Command::new("gh")
-                .env("GH_TOKEN", gh_token)
-                .args([
-                    "label",
-                    "create",
-                    label,
-                    "-R",
-                    repo,
-                    "--description",
-                    "Infrastructure automation"
-                ])
-                .output();
-        }
+/// Lists every label of `repo`, lowercased for case-insensitive matching.
+async fn list_label_names(octocrab: &Octocrab, repo: &str) -> Result<HashSet<String>, AppError> {
+    let (owner, name) = split_repository(repo)?;
+    let first_page = octocrab
+        .issues(owner, name)
+        .list_labels_for_repo()
+        .per_page(100)
+        .send()
+        .await
+        .map_err(|e| AppError::service(format!("failed to list labels for {repo}: {e}")))?;
+
+    Ok(octocrab
+        .all_pages(first_page)
+        .await
+        .map_err(|e| AppError::service(format!("failed to list labels for {repo}: {e}")))?
+        .into_iter()
+        .map(|label| label.name.to_lowercase())
+        .collect())
+}
+
+async fn pr_create(
+    gh: &GhCli<'_>,
+    octocrab: &Octocrab,
+    request: &PrRequest<'_>
+) -> Result<PrCreateResult, AppError> {
+    let PrRequest {
+        repo,
+        head,
+        base,
+        ..
+    } = *request;
+
+    if let Some(pr_number) = gh.existing_pr(repo, head)? {
+        return Ok(PrCreateResult {
+            created:   false,
+            pr_number: Some(pr_number),
+            pr_url:    None,
+            message:   format!("PR #{pr_number} already open for {repo}:{head} -> {base}")
+        });
     }
 
-    Ok(())
+    let wanted: Vec<Label> = request.labels.iter().copied().map(Label::new).collect();
+    let report = ensure_labels(octocrab, repo, &wanted).await?;
+    debug!(
+        "Labels for {repo}: created {:?}, existing {:?}",
+        report.created, report.existing
+    );
+
+    let pr_url = gh.create_pr(request)?;
+
+    Ok(PrCreateResult {
+        created:   true,
+        pr_number: None,
+        pr_url:    Some(pr_url.clone()),
+        message:   format!("Created PR: {pr_url}")
+    })
 }
 
-fn create_pr(
+async fn pr_probe(
+    gh: &GhCli<'_>,
+    octocrab: &Octocrab,
     repo: &str,
     head: &str,
     base: &str,
-    title: &str,
-    body: &str,
-    labels: &[&str],
-    gh_token: &str
-) -> Result<String, AppError> {
-    let mut args = vec![
-        "pr", "create", "-R", repo, "--head", head, "--base", base, "--title", title, "--body",
-        body,
-    ];
+    labels: &[&str]
+) -> Result<PrProbeResult, AppError> {
+    let existing_pr = gh.existing_pr(repo, head)?;
+    let known = list_label_names(octocrab, repo).await?;
 
-    let label_args: Vec<String> = labels
+    let (existing_labels, missing_labels): (Vec<String>, Vec<String>) = labels
         .iter()
-        .flat_map(|label| vec!["--label".to_string(), (*label).to_string()])
-        .collect();
+        .map(|label| (*label).to_string())
+        .partition(|label| known.contains(&label.to_lowercase()));
 
-    let label_arg_refs: Vec<&str> = label_args.iter().map(std::string::String::as_str).collect();
-    args.extend(label_arg_refs);
+    let message = match existing_pr {
+        Some(pr_number) => format!("PR #{pr_number} already open for {repo}:{head} -> {base}"),
+        None => format!("PR would be created for {repo}:{head} -> {base}")
+    };
 
-    let output = Command::new("gh")
-        .env("GH_TOKEN", gh_token)
-        .args(&args)
-        .output()
-        .map_err(|e| AppError::service(format!("gh pr create failed: {e}")))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(AppError::service(format!("gh pr create failed: {stderr}")));
-    }
-
-    let pr_url = String::from_utf8_lossy(&output.stdout).trim().to_string();
-
-    Ok(pr_url)
+    Ok(PrProbeResult {
+        created: false,
+        would_create: existing_pr.is_none(),
+        pr_number: existing_pr,
+        existing_labels,
+        missing_labels,
+        message
+    })
 }
 
 #[cfg(test)]
@@ -227,4 +512,288 @@ mod tests {
         assert_eq!(result.created, cloned.created);
         assert_eq!(result.pr_number, cloned.pr_number);
     }
+
+    #[test]
+    fn pr_probe_result_serialization() {
+        let result = PrProbeResult {
+            created:         false,
+            would_create:    true,
+            pr_number:       None,
+            existing_labels: vec!["ci".to_string()],
+            missing_labels:  vec!["metrics".to_string()],
+            message:         "PR would be created".to_string()
+        };
+
+        let json = serde_json::to_string(&result).expect("serialization failed");
+        assert!(json.contains("\"created\":false"));
+        assert!(json.contains("\"would_create\":true"));
+        assert!(json.contains("\"missing_labels\":[\"metrics\"]"));
+    }
+
+    /// Writes a fake `gh` executable that records every invocation in
+    /// `calls.log` and reports `existing_pr` from `pr list`.
+    #[cfg(unix)]
+    fn fake_gh(directory: &std::path::Path, existing_pr: &str) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        let log = directory.join("calls.log");
+        let script = directory.join("gh");
+        let contents = format!(
+            "#!/bin/sh\necho \"$*\" >> '{}'\ncase \"$1 $2\" in\n  'pr list') echo '{existing_pr}' ;;\nesac\n",
+            log.display()
+        );
+        std::fs::write(&script, contents).expect("write fake gh");
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod fake gh");
+        script
+    }
+
+    fn mock_octocrab(server: &wiremock::MockServer) -> Octocrab {
+        Octocrab::builder()
+            .personal_token("test-token")
+            .base_uri(server.uri())
+            .expect("base_uri")
+            .build()
+            .expect("octocrab build")
+    }
+
+    fn label_json(name: &str) -> String {
+        format!(
+            r#"{{"id":1,"node_id":"l","url":"https://example.com/l","name":"{name}","description":null,"color":"ededed","default":false}}"#
+        )
+    }
+
+    /// Mounts a label listing that knows only the `CI` label.
+    async fn mount_label_listing(server: &wiremock::MockServer) {
+        use wiremock::{
+            Mock, ResponseTemplate,
+            matchers::{method, path}
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/labels"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(format!("[{}]", label_json("CI")), "application/json")
+            )
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    /// Mounts a labels API that knows only the `CI` label and accepts one
+    /// creation of `metrics`.
+    async fn mount_labels_api(server: &wiremock::MockServer) {
+        use wiremock::{
+            Mock, ResponseTemplate,
+            matchers::{body_partial_json, method, path}
+        };
+
+        mount_label_listing(server).await;
+        Mock::given(method("POST"))
+            .and(path("/repos/owner/repo/labels"))
+            .and(body_partial_json(serde_json::json!({"name": "metrics"})))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_raw(label_json("metrics"), "application/json")
+            )
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn ensure_labels_creates_only_missing_labels() {
+        let server = wiremock::MockServer::start().await;
+        mount_labels_api(&server).await;
+
+        let report = ensure_labels(
+            &mock_octocrab(&server),
+            "owner/repo",
+            &[Label::new("ci"), Label::new("metrics")]
+        )
+        .await
+        .expect("labels ensured");
+
+        assert_eq!(report.existing, ["ci"]);
+        assert_eq!(report.created, ["metrics"]);
+    }
+
+    #[tokio::test]
+    async fn ensure_labels_surfaces_creation_failures() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path}
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/labels"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw("[]", "application/json"))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/owner/repo/labels"))
+            .respond_with(ResponseTemplate::new(403).set_body_raw(
+                r#"{"message":"Resource not accessible by integration"}"#,
+                "application/json"
+            ))
+            .mount(&server)
+            .await;
+
+        let error = ensure_labels(&mock_octocrab(&server), "owner/repo", &[Label::new("ci")])
+            .await
+            .expect_err("creation failure surfaced");
+
+        let message = error.message.as_deref().unwrap_or_default();
+        assert!(
+            message.contains("failed to create label 'ci' in owner/repo"),
+            "unexpected error: {message}"
+        );
+    }
+
+    #[tokio::test]
+    async fn ensure_labels_rejects_malformed_repository() {
+        let server = wiremock::MockServer::start().await;
+
+        let error = ensure_labels(&mock_octocrab(&server), "owner", &[Label::new("ci")])
+            .await
+            .expect_err("malformed repository rejected");
+
+        assert_eq!(error.message.as_deref(), Some("invalid repository 'owner'"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn pr_create_creates_missing_labels_and_pr() {
+        let directory = tempfile::tempdir().expect("tempdir");
+        let gh = fake_gh(directory.path(), "");
+        let program = gh.to_str().expect("utf8 path");
+        let server = wiremock::MockServer::start().await;
+        mount_labels_api(&server).await;
+
+        let request = PrRequest {
+            repo:   "owner/repo",
+            head:   "feature",
+            base:   "main",
+            title:  "title",
+            body:   "body",
+            labels: &["ci", "metrics"]
+        };
+
+        let result = pr_create(
+            &GhCli::new(program, "token"),
+            &mock_octocrab(&server),
+            &request
+        )
+        .await
+        .expect("create should succeed");
+        assert!(result.created);
+
+        let calls = std::fs::read_to_string(directory.path().join("calls.log")).expect("log");
+        assert!(!calls.lines().any(|line| line.starts_with("label ")));
+        assert!(
+            calls.lines().any(|line| line.starts_with("pr create")
+                && line.ends_with("--label ci --label metrics"))
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn pr_probe_never_invokes_create_path() {
+        let directory = tempfile::tempdir().expect("tempdir");
+        let gh = fake_gh(directory.path(), "");
+        let program = gh.to_str().expect("utf8 path");
+        let server = wiremock::MockServer::start().await;
+        mount_label_listing(&server).await;
+
+        let report = pr_probe(
+            &GhCli::new(program, "token"),
+            &mock_octocrab(&server),
+            "owner/repo",
+            "feature",
+            "main",
+            &["ci", "metrics"]
+        )
+        .await
+        .expect("probe should succeed");
+
+        assert!(!report.created);
+        assert!(report.would_create);
+        assert_eq!(report.pr_number, None);
+        assert_eq!(report.existing_labels, ["ci"]);
+        assert_eq!(report.missing_labels, ["metrics"]);
+
+        let calls = std::fs::read_to_string(directory.path().join("calls.log")).expect("log");
+        assert!(calls.lines().any(|line| line.starts_with("pr list")));
+        assert!(
+            !calls
+                .lines()
+                .any(|line| line.starts_with("pr create") || line.starts_with("label ")),
+            "probe must not mutate, got calls:\n{calls}"
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn pr_probe_reports_existing_pr() {
+        let directory = tempfile::tempdir().expect("tempdir");
+        let gh = fake_gh(directory.path(), "42");
+        let program = gh.to_str().expect("utf8 path");
+        let server = wiremock::MockServer::start().await;
+        mount_label_listing(&server).await;
+
+        let report = pr_probe(
+            &GhCli::new(program, "token"),
+            &mock_octocrab(&server),
+            "owner/repo",
+            "feature",
+            "main",
+            &[]
+        )
+        .await
+        .expect("probe should succeed");
+
+        assert!(!report.created);
+        assert!(!report.would_create);
+        assert_eq!(report.pr_number, Some(42));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn pr_probe_surfaces_label_listing_failures() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path}
+        };
+
+        let directory = tempfile::tempdir().expect("tempdir");
+        let gh = fake_gh(directory.path(), "");
+        let program = gh.to_str().expect("utf8 path");
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/labels"))
+            .respond_with(
+                ResponseTemplate::new(401)
+                    .set_body_raw(r#"{"message":"Bad credentials"}"#, "application/json")
+            )
+            .mount(&server)
+            .await;
+
+        let error = pr_probe(
+            &GhCli::new(program, "token"),
+            &mock_octocrab(&server),
+            "owner/repo",
+            "feature",
+            "main",
+            &["ci"]
+        )
+        .await
+        .expect_err("listing failure surfaced");
+
+        let message = error.message.as_deref().unwrap_or_default();
+        assert!(
+            message.starts_with("failed to list labels for owner/repo"),
+            "unexpected error: {message}"
+        );
+    }
 }
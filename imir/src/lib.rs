@@ -47,6 +47,7 @@ mod error;
 mod file;
 mod gh;
 mod git;
+mod lint;
 mod normalizer;
 mod open_source;
 mod readme;
@@ -56,34 +57,50 @@ mod slug;
 mod slugs;
 mod svg;
 mod sync;
+mod warning;
 
 pub use artifact::{ArtifactLocation, locate_artifact};
-pub use badge::{BadgeAssets, generate_badge_assets};
+pub use badge::{
+    BadgeAssets, cache_remote_avatars, generate_badge_assets, resolve_local_avatars,
+    validate_badge, warn_skipped_avatars
+};
 pub use config::{
-    BadgeOptions, BadgeStyle, BadgeWidgetAlignment, BadgeWidgetOptions, TargetConfig, TargetEntry,
-    TargetKind
+    BadgeOptions, BadgeStyle, BadgeWidgetAlignment, BadgeWidgetOptions, TargetConfig,
+    TargetDefaults, TargetEntry, TargetKind
+};
+pub use contributors::{
+    ContributorActivity, ContributorSummary, ContributorWeeklyActivity, RepositoryFetchFailure,
+    fetch_contributor_activity, fetch_contributor_weekly_activity, format_sparkline,
+    summarize_contributors
 };
-pub use contributors::{ContributorActivity, fetch_contributor_activity};
 pub use discover::{
-    DiscoveredRepository, DiscoveryConfig, discover_badge_users, discover_stargazer_repositories,
-    extract_repo_from_readme
+    DiscoveredRepository, DiscoveryConfig, DiscoverySample, DiscoverySummary,
+    discover_badge_users, discover_stargazer_repositories, extract_repo_from_readme,
+    sample_repositories, sample_repository_pages, stargazer_repository_pages
 };
 pub use error::{Error, io_error};
 pub use file::{FileMoveResult, move_file};
-pub use gh::{PrCreateResult, gh_pr_create};
+pub use gh::{
+    EnsureReport, Label, PrCreateResult, PrProbeResult, ensure_labels, gh_pr_create, gh_pr_probe
+};
 pub use git::{GitPushResult, git_commit_push};
+pub use lint::{LintFinding, LintId, lint_targets};
 pub use normalizer::{
-    BadgeDescriptor, BadgeWidgetDescriptor, RenderTarget, TargetsDocument, load_targets,
-    parse_targets
+    BadgeDescriptor, BadgeWidgetDescriptor, FieldProvenance, ParseLimits, RenderTarget,
+    RenderTargetKey, RenderTargetProvenance, TargetsDocument, explain_target, explain_targets,
+    load_privacy_policy, load_targets, load_targets_with_limits, parse_targets,
+    parse_targets_with_limits
 };
 pub use open_source::{
     OpenSourceRepository, resolve_open_source_repositories, resolve_open_source_targets
 };
-pub use readme::update_readme;
+pub use readme::{BrokenLink, check_metrics_links, metrics_links, update_readme};
 pub use render::{
-    ProfileInputs, RepositoryInputs, normalize_profile_inputs, normalize_repository_inputs
+    PrivacyMapping, PrivacyPolicy, ProfileInputs, ProfileRequest, RepositoryInputs,
+    normalize_profile_inputs, normalize_repository_inputs
 };
 pub use slug::SlugStrategy;
 pub use slugs::{SlugDetectionResult, detect_impacted_slugs};
 pub use svg::{SvgOptimizeResult, optimize_svg};
 pub use sync::sync_targets;
+pub use warning::WarningCollector;
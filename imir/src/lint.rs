@@ -0,0 +1,371 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Heuristic checks that flag targets likely to render ugly badges.
+//!
+//! Lints never reject a configuration: they inspect normalized targets along
+//! with their [`RenderTargetProvenance`] and report findings that callers
+//! surface as warnings. Every lint has a stable [`LintId`] so individual
+//! checks can be suppressed.
+
+use std::{fmt, str::FromStr};
+
+use crate::{
+    badge,
+    config::TargetKind,
+    normalizer::{FieldProvenance, RenderTarget, RenderTargetProvenance}
+};
+
+/// Minimum contrast ratio between white badge text and the background
+/// (WCAG AA for normal text).
+const MIN_CONTRAST_RATIO: f64 = 4.5;
+
+/// Identifier of a single lint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintId {
+    /// The `owner/repository` label is wider than the badge card.
+    LongLabel,
+    /// The badge color override has poor contrast against white text.
+    LowContrast,
+    /// A profile target sets fields that only apply to repositories.
+    ProfileRepositoryFields
+}
+
+impl LintId {
+    /// Every lint, in reporting order.
+    pub const ALL: [Self; 3] = [
+        Self::LongLabel,
+        Self::LowContrast,
+        Self::ProfileRepositoryFields
+    ];
+
+    /// Returns the stable identifier used for suppression.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::LongLabel => "long-label",
+            Self::LowContrast => "low-contrast",
+            Self::ProfileRepositoryFields => "profile-repo-fields"
+        }
+    }
+}
+
+impl fmt::Display for LintId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for LintId {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|lint| lint.as_str() == value)
+            .ok_or_else(|| {
+                let known: Vec<&str> = Self::ALL.iter().map(|lint| lint.as_str()).collect();
+                format!(
+                    "unknown lint '{value}'; expected one of: {}",
+                    known.join(", ")
+                )
+            })
+    }
+}
+
+/// Single lint finding for a target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintFinding {
+    /// Slug of the offending target.
+    pub slug:    String,
+    /// Lint that produced the finding.
+    pub lint:    LintId,
+    /// Human readable description of the problem.
+    pub message: String
+}
+
+impl fmt::Display for LintFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} [{}]", self.slug, self.message, self.lint)
+    }
+}
+
+/// Runs every lint not listed in `allowed` over `targets`.
+///
+/// # Arguments
+///
+/// * `targets` - Normalized targets paired with their provenance, as returned
+///   by [`explain_targets`](crate::explain_targets)
+/// * `allowed` - Lints to suppress
+///
+/// # Returns
+///
+/// Findings ordered by target, then by [`LintId::ALL`].
+///
+/// # Examples
+///
+/// ```
+/// use imir::{LintId, explain_targets, lint_targets};
+///
+/// let yaml = r"
+/// targets:
+///   - owner: octocat
+///     repo: metrics
+///     type: profile
+/// ";
+/// let targets = explain_targets(yaml).expect("valid configuration");
+///
+/// let findings = lint_targets(&targets, &[]);
+/// assert_eq!(findings[0].lint, LintId::ProfileRepositoryFields);
+/// assert!(lint_targets(&targets, &[LintId::ProfileRepositoryFields]).is_empty());
+/// ```
+#[must_use]
+pub fn lint_targets(
+    targets: &[(RenderTarget, RenderTargetProvenance)],
+    allowed: &[LintId]
+) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+
+    for (target, provenance) in targets {
+        for lint in LintId::ALL {
+            if allowed.contains(&lint) {
+                continue;
+            }
+            if let Some(message) = check(lint, target, provenance) {
+                findings.push(LintFinding {
+                    slug: target.slug.clone(),
+                    lint,
+                    message
+                });
+            }
+        }
+    }
+
+    findings
+}
+
+fn check(
+    lint: LintId,
+    target: &RenderTarget,
+    provenance: &RenderTargetProvenance
+) -> Option<String> {
+    match lint {
+        LintId::LongLabel => badge::label_overflow(target).map(|(width, available)| {
+            format!("badge label is ~{width}px wide and will overflow the {available}px card")
+        }),
+        LintId::LowContrast => {
+            let color = target.badge.color.as_deref()?;
+            let ratio = contrast_ratio(parse_hex(color)?, [0xff, 0xff, 0xff]);
+            (ratio < MIN_CONTRAST_RATIO).then(|| {
+                format!(
+                    "badge.color {color} has a {ratio:.2}:1 contrast ratio against white text \
+                     (minimum {MIN_CONTRAST_RATIO}:1)"
+                )
+            })
+        }
+        LintId::ProfileRepositoryFields => {
+            if target.kind != TargetKind::Profile {
+                return None;
+            }
+            let fields: Vec<&str> = [
+                ("repository", provenance.repository),
+                ("contributors_branch", provenance.contributors_branch)
+            ]
+            .into_iter()
+            .filter(|(_, origin)| *origin == FieldProvenance::Override)
+            .map(|(field, _)| field)
+            .collect();
+            (!fields.is_empty()).then(|| {
+                format!(
+                    "profile targets ignore repository-only fields: {}",
+                    fields.join(", ")
+                )
+            })
+        }
+    }
+}
+
+fn parse_hex(color: &str) -> Option<[u8; 3]> {
+    let digits = color.strip_prefix('#')?;
+    let channel = |index: usize| u8::from_str_radix(digits.get(index..index + 2)?, 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// WCAG contrast ratio between two sRGB colors.
+fn contrast_ratio(first: [u8; 3], second: [u8; 3]) -> f64 {
+    let (first, second) = (relative_luminance(first), relative_luminance(second));
+    let (lighter, darker) = if first > second {
+        (first, second)
+    } else {
+        (second, first)
+    };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+fn relative_luminance(rgb: [u8; 3]) -> f64 {
+    let [r, g, b] = rgb.map(|channel| {
+        let value = f64::from(channel) / 255.0;
+        if value <= 0.039_28 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    });
+    0.0722f64.mul_add(b, 0.2126f64.mul_add(r, 0.7152 * g))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::explain_targets;
+
+    fn lint_yaml(yaml: &str, allowed: &[LintId]) -> Vec<LintFinding> {
+        let targets = explain_targets(yaml).expect("valid configuration");
+        lint_targets(&targets, allowed)
+    }
+
+    #[test]
+    fn long_label_is_flagged() {
+        let findings = lint_yaml(
+            r"
+            targets:
+              - owner: an-organization-with-a-long-name
+                repo: and-an-even-longer-repository-name
+                slug: long
+                type: open_source
+              - owner: octocat
+                repo: metrics
+                type: open_source
+            ",
+            &[]
+        );
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].slug, "long");
+        assert_eq!(findings[0].lint, LintId::LongLabel);
+        assert!(findings[0].message.contains("will overflow the 400px card"));
+    }
+
+    #[test]
+    fn low_contrast_color_is_flagged() {
+        let findings = lint_yaml(
+            r"
+            targets:
+              - owner: octocat
+                repo: pale
+                type: open_source
+                badge:
+                  color: '#f0f0f0'
+              - owner: octocat
+                repo: dark
+                type: open_source
+                badge:
+                  color: '#0a3069'
+              - owner: octocat
+                repo: plain
+                type: open_source
+            ",
+            &[]
+        );
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].slug, "pale");
+        assert_eq!(findings[0].lint, LintId::LowContrast);
+        assert_eq!(
+            findings[0].message,
+            "badge.color #f0f0f0 has a 1.14:1 contrast ratio against white text (minimum 4.5:1)"
+        );
+    }
+
+    #[test]
+    fn profile_repository_fields_are_flagged() {
+        let findings = lint_yaml(
+            r"
+            targets:
+              - owner: octocat
+                type: profile
+                contributors_branch: develop
+              - owner: hubot
+                repo: dotfiles
+                type: profile
+              - owner: octocat
+                repo: metrics
+                type: open_source
+                contributors_branch: develop
+            ",
+            &[]
+        );
+
+        let messages: Vec<(&str, &str)> = findings
+            .iter()
+            .map(|finding| {
+                assert_eq!(finding.lint, LintId::ProfileRepositoryFields);
+                (finding.slug.as_str(), finding.message.as_str())
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                (
+                    "octocat-profile",
+                    "profile targets ignore repository-only fields: contributors_branch"
+                ),
+                (
+                    "hubot-profile",
+                    "profile targets ignore repository-only fields: repository"
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn allowed_lints_are_suppressed() {
+        let yaml = r"
+            targets:
+              - owner: an-organization-with-a-long-name
+                repo: and-an-even-longer-repository-name
+                type: open_source
+                badge:
+                  color: '#ffffff'
+              - owner: octocat
+                repo: metrics
+                type: profile
+            ";
+
+        let lints: Vec<LintId> = lint_yaml(yaml, &[])
+            .into_iter()
+            .map(|finding| finding.lint)
+            .collect();
+        assert_eq!(
+            lints,
+            [
+                LintId::LongLabel,
+                LintId::LowContrast,
+                LintId::ProfileRepositoryFields
+            ]
+        );
+        assert_eq!(lint_yaml(yaml, &[LintId::LowContrast]).len(), 2);
+        assert!(lint_yaml(yaml, &LintId::ALL).is_empty());
+    }
+
+    #[test]
+    fn lint_ids_round_trip_through_strings() {
+        for lint in LintId::ALL {
+            assert_eq!(lint.as_str().parse::<LintId>(), Ok(lint));
+        }
+        assert!(
+            "bogus"
+                .parse::<LintId>()
+                .unwrap_err()
+                .contains("expected one of: long-label, low-contrast, profile-repo-fields")
+        );
+    }
+
+    #[test]
+    fn contrast_ratio_matches_reference_values() {
+        let black_on_white = contrast_ratio([0, 0, 0], [0xff, 0xff, 0xff]);
+        assert!((black_on_white - 21.0).abs() < 1e-9);
+        assert!((contrast_ratio([0xff, 0xff, 0xff], [0xff, 0xff, 0xff]) - 1.0).abs() < 1e-9);
+    }
+}
@@ -8,20 +8,34 @@
 //! and resolving workflow inputs specific to open-source repository rendering.
 
 use std::{
-    io,
+    fs, io,
+    num::NonZeroUsize,
     path::{Path, PathBuf},
     process
 };
 
 use clap::{ArgAction, Args, Parser, Subcommand};
+use futures::{StreamExt as _, stream::BoxStream};
 use imir::{
-    DiscoveryConfig, Error, TargetsDocument, detect_impacted_slugs, discover_badge_users,
-    discover_stargazer_repositories, generate_badge_assets, gh_pr_create, git_commit_push,
-    load_targets, locate_artifact, move_file, normalize_profile_inputs,
-    normalize_repository_inputs, optimize_svg, resolve_open_source_repositories, sync_targets
+    ContributorWeeklyActivity, DiscoveryConfig, Error, FieldProvenance, LintId, PrivacyPolicy,
+    ProfileRequest, RenderTarget, RenderTargetProvenance, TargetsDocument, WarningCollector,
+    detect_impacted_slugs, discover_badge_users, discover_stargazer_repositories, explain_target,
+    format_sparkline, generate_badge_assets, gh_pr_create, gh_pr_probe, git_commit_push,
+    load_privacy_policy, load_targets, locate_artifact, move_file, normalize_profile_inputs,
+    normalize_repository_inputs, optimize_svg, resolve_open_source_repositories,
+    sample_repository_pages, stargazer_repository_pages, sync_targets, validate_badge,
+    warn_skipped_avatars
 };
+use masterror::AppError;
 use tracing::info;
 
+/// Default bound on concurrent repository fetches for `summary`.
+const DEFAULT_SUMMARY_CONCURRENCY: NonZeroUsize =
+    NonZeroUsize::new(imir::contributors::DEFAULT_SUMMARY_CONCURRENCY).unwrap();
+
+/// Number of contributors rendered by `contributors --graph`.
+const CONTRIBUTOR_GRAPH_LIMIT: usize = 10;
+
 /// Command line interface for generating normalized metrics target definitions.
 #[derive(Debug, Parser)]
 #[command(name = "imir", version, about = "Normalize metrics renderer targets")]
@@ -30,6 +44,10 @@ struct Cli {
     #[command(subcommand)]
     command: Option<Command>,
 
+    /// Exit with a non-zero status when the command emitted any warnings.
+    #[arg(long = "fail-on-warnings", global = true, action = ArgAction::SetTrue)]
+    fail_on_warnings: bool,
+
     /// Legacy argument support for the default targets command.
     #[command(flatten)]
     legacy: LegacyTargetsArgs
@@ -53,6 +71,8 @@ enum Command {
     Readme(ReadmeArgs),
     /// Show contributor activity for the last 30 days.
     Contributors(ContributorsArgs),
+    /// Aggregate contributor activity across every repository target.
+    Summary(SummaryArgs),
     /// Detect impacted slugs from git changes.
     Slugs(SlugsArgs),
     /// Locate generated metrics artifacts.
@@ -71,16 +91,50 @@ enum Command {
 
 #[derive(Debug, Args)]
 /// Arguments accepted by the `targets` subcommand.
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct TargetsArgs {
+    #[command(subcommand)]
+    command: Option<TargetsCommand>,
+
     /// Path to the YAML configuration file describing metrics targets.
-    #[arg(long = "config", value_name = "PATH")]
-    config: PathBuf,
+    #[arg(long = "config", value_name = "PATH", required = true)]
+    config: Option<PathBuf>,
 
     /// Output formatted JSON for easier inspection.
     #[arg(long = "pretty", action = ArgAction::SetTrue)]
     pretty: bool
 }
 
+#[derive(Debug, Subcommand)]
+enum TargetsCommand {
+    /// Show how each normalized field of a target was derived.
+    Explain(TargetsExplainArgs),
+    /// Warn about targets likely to produce ugly badges.
+    Lint(TargetsLintArgs)
+}
+
+#[derive(Debug, Args)]
+struct TargetsExplainArgs {
+    /// Slug of the target to explain.
+    #[arg(value_name = "SLUG")]
+    slug: String,
+
+    /// Path to the YAML configuration file describing metrics targets.
+    #[arg(long = "config", value_name = "PATH")]
+    config: PathBuf
+}
+
+#[derive(Debug, Args)]
+struct TargetsLintArgs {
+    /// Path to the YAML configuration file describing metrics targets.
+    #[arg(long = "config", value_name = "PATH")]
+    config: PathBuf,
+
+    /// Suppress a lint by id (long-label, low-contrast, profile-repo-fields).
+    #[arg(long = "allow", value_name = "LINT")]
+    allow: Vec<LintId>
+}
+
 /// Arguments accepted when the CLI is invoked without a subcommand.
 #[derive(Debug, Args, Default)]
 struct LegacyTargetsArgs {
@@ -126,7 +180,10 @@ struct BadgeGenerateArgs {
 
     /// Directory that will receive the SVG and manifest artifacts.
     #[arg(long = "output", value_name = "DIR", default_value = "metrics")]
-    output: PathBuf
+    output: PathBuf,
+
+    #[command(flatten)]
+    avatars: AvatarCacheArgs
 }
 
 #[derive(Debug, Args)]
@@ -137,7 +194,29 @@ struct BadgeGenerateAllArgs {
 
     /// Directory that will receive the SVG and manifest artifacts.
     #[arg(long = "output", value_name = "DIR", default_value = "metrics")]
-    output: PathBuf
+    output: PathBuf,
+
+    /// Render and check every badge in memory without writing artifacts.
+    #[arg(long = "validate-only", action = ArgAction::SetTrue)]
+    validate_only: bool,
+
+    #[command(flatten)]
+    avatars: AvatarCacheArgs
+}
+
+#[derive(Debug, Args)]
+struct AvatarCacheArgs {
+    /// Download remote profile avatars into the cache before rendering.
+    #[arg(long = "fetch-avatars", action = ArgAction::SetTrue)]
+    fetch_avatars: bool,
+
+    /// Directory caching downloaded avatars between runs.
+    #[arg(
+        long = "avatar-cache",
+        value_name = "DIR",
+        default_value = ".metrics-tmp/avatars"
+    )]
+    avatar_cache: PathBuf
 }
 
 #[derive(Debug, Args)]
@@ -156,7 +235,23 @@ struct DiscoverArgs {
 
     /// Maximum number of pages to fetch from GitHub API.
     #[arg(long = "max-pages", value_name = "COUNT", default_value = "10")]
-    max_pages: u32
+    max_pages: u32,
+
+    /// Print a machine-parseable `discovered=N source=S elapsed_ms=T`
+    /// summary line to stderr once discovery finishes.
+    #[arg(long = "plain", action = ArgAction::SetTrue)]
+    plain: bool,
+
+    /// Output a random sample of at most N discovered repositories.
+    ///
+    /// Pages are sampled as they arrive, keeping only N repositories in
+    /// memory; bound the scan with `--max-pages`.
+    #[arg(long = "sample", value_name = "N")]
+    sample: Option<usize>,
+
+    /// Seed for `--sample`; defaults to a time-based seed.
+    #[arg(long = "seed", value_name = "SEED", requires = "sample")]
+    seed: Option<u64>
 }
 
 #[derive(Debug, Args)]
@@ -186,22 +281,81 @@ struct ReadmeArgs {
 
     /// Path to the YAML configuration file describing metrics targets.
     #[arg(long = "config", value_name = "PATH")]
-    config: PathBuf
+    config: PathBuf,
+
+    /// Issue HEAD requests to every generated metrics URL and warn about
+    /// links that do not answer with 200 OK.
+    #[arg(long = "check-links", action = ArgAction::SetTrue)]
+    check_links: bool
 }
 
 #[derive(Debug, Args)]
 struct ContributorsArgs {
+    /// Repository in `owner/repo` form; shorthand for `--owner` and `--repo`.
+    #[arg(
+        value_name = "OWNER/REPO",
+        value_parser = parse_repository_slug,
+        conflicts_with_all = ["owner", "repo"],
+        required_unless_present_all = ["owner", "repo"]
+    )]
+    repository: Option<(String, String)>,
+
     /// Repository owner.
-    #[arg(long = "owner", value_name = "OWNER")]
-    owner: String,
+    #[arg(long = "owner", value_name = "OWNER", requires = "repo")]
+    owner: Option<String>,
 
     /// Repository name.
-    #[arg(long = "repo", value_name = "REPO")]
-    repo: String,
+    #[arg(long = "repo", value_name = "REPO", requires = "owner")]
+    repo: Option<String>,
+
+    /// GitHub personal access token for API authentication.
+    #[arg(long = "token", env = "GITHUB_TOKEN")]
+    token: String,
+
+    /// Render a sparkline of weekly commits per top contributor instead of
+    /// JSON.
+    #[arg(long = "graph", action = ArgAction::SetTrue)]
+    graph: bool
+}
+
+impl ContributorsArgs {
+    /// Returns the `(owner, repo)` pair from either the positional shorthand
+    /// or the long flags.
+    fn owner_repo(&self) -> Result<(&str, &str), Error> {
+        match (&self.repository, &self.owner, &self.repo) {
+            (Some((owner, repo)), ..) | (None, Some(owner), Some(repo)) => Ok((owner, repo)),
+            _ => Err(Error::validation(
+                "either OWNER/REPO or both --owner and --repo must be provided"
+            ))
+        }
+    }
+}
+
+/// Splits an `owner/repo` slug into its owner and repository names.
+fn parse_repository_slug(value: &str) -> Result<(String, String), String> {
+    let valid = |part: &str| !part.is_empty() && !part.contains(['/', ' ', '\t']);
+
+    match value.split_once('/') {
+        Some((owner, repo)) if valid(owner) && valid(repo) => {
+            Ok((owner.to_owned(), repo.to_owned()))
+        }
+        _ => Err(format!("expected OWNER/REPO, got '{value}'"))
+    }
+}
+
+#[derive(Debug, Args)]
+struct SummaryArgs {
+    /// Path to the YAML configuration file describing metrics targets.
+    #[arg(long = "config", value_name = "PATH")]
+    config: PathBuf,
 
     /// GitHub personal access token for API authentication.
     #[arg(long = "token", env = "GITHUB_TOKEN")]
-    token: String
+    token: String,
+
+    /// Maximum number of repositories fetched concurrently.
+    #[arg(long = "concurrency", value_name = "N", default_value_t = DEFAULT_SUMMARY_CONCURRENCY)]
+    concurrency: NonZeroUsize
 }
 
 #[derive(Debug, Args)]
@@ -330,7 +484,11 @@ struct GhPrCreateArgs {
 
     /// GitHub token.
     #[arg(long = "token", value_name = "TOKEN", required = true)]
-    token: String
+    token: String,
+
+    /// Report existing PR and label state without creating anything.
+    #[arg(long = "probe", action = ArgAction::SetTrue)]
+    probe: bool
 }
 
 #[derive(Debug, Args)]
@@ -370,7 +528,12 @@ struct NormalizeProfileArgs {
     display_name: Option<String>,
 
     #[arg(long = "include-private", value_name = "BOOL")]
-    include_private: Option<String>
+    include_private: Option<String>,
+
+    /// Targets configuration whose `privacy` section overrides the default
+    /// plugin mappings.
+    #[arg(long = "config", value_name = "PATH")]
+    config: Option<PathBuf>
 }
 
 #[derive(Debug, Args)]
@@ -443,29 +606,133 @@ async fn main() {
 ///
 /// Propagates errors originating from configuration loading and normalization.
 async fn run() -> Result<(), Error> {
-    let cli = Cli::parse();
+    run_cli(Cli::parse()).await
+}
+
+/// Dispatches the parsed CLI to the matching command runner.
+///
+/// Warnings emitted by the runner are collected and, when
+/// `--fail-on-warnings` is set, escalated into an error once the command
+/// completes successfully.
+///
+/// # Errors
+///
+/// Propagates errors returned by the command runner and returns
+/// [`Error::Validation`] when warnings were emitted under
+/// `--fail-on-warnings`.
+async fn run_cli(cli: Cli) -> Result<(), Error> {
+    let mut warnings = WarningCollector::new();
 
     match cli.command {
-        Some(Command::Targets(args)) => run_targets(&args),
+        Some(Command::Targets(args)) => run_targets(&args, &mut warnings),
         Some(Command::OpenSource(args)) => run_open_source(&args),
-        Some(Command::Badge(args)) => run_badge(args),
+        Some(Command::Badge(args)) => run_badge(args, &mut warnings).await,
         Some(Command::Discover(args)) => run_discover(args).await,
         Some(Command::Sync(args)) => run_sync(args).await,
-        Some(Command::Readme(args)) => run_readme(&args),
+        Some(Command::Readme(args)) => run_readme(&args, &mut warnings).await,
         Some(Command::Contributors(args)) => run_contributors(args).await,
+        Some(Command::Summary(args)) => run_summary(args, &mut warnings).await,
         Some(Command::Slugs(args)) => run_slugs(&args),
         Some(Command::Artifact(args)) => run_artifact(&args),
         Some(Command::File(args)) => run_file(args),
         Some(Command::Git(args)) => run_git(args),
-        Some(Command::Gh(args)) => run_gh(args),
+        Some(Command::Gh(args)) => run_gh(args).await,
         Some(Command::Render(args)) => run_render(args),
         Some(Command::Svg(args)) => run_svg(args),
-        None => run_legacy_targets(&cli.legacy)
+        None => run_legacy_targets(&cli.legacy, &mut warnings)
+    }?;
+
+    if cli.fail_on_warnings {
+        warnings.ensure_empty()?;
     }
+
+    Ok(())
+}
+
+fn run_targets(args: &TargetsArgs, warnings: &mut WarningCollector) -> Result<(), Error> {
+    match &args.command {
+        Some(TargetsCommand::Explain(explain_args)) => run_targets_explain(explain_args),
+        Some(TargetsCommand::Lint(lint_args)) => run_targets_lint(lint_args, warnings),
+        None => {
+            let config = args
+                .config
+                .as_deref()
+                .ok_or_else(|| Error::validation("--config is required"))?;
+            run_targets_from_path(config, args.pretty)
+        }
+    }
+}
+
+fn run_targets_explain(args: &TargetsExplainArgs) -> Result<(), Error> {
+    let contents =
+        fs::read_to_string(&args.config).map_err(|source| imir::io_error(&args.config, source))?;
+    let (target, provenance) = explain_target(&contents, &args.slug)?;
+
+    let stdout = io::stdout();
+    let mut handle = stdout.lock();
+    write_target_explanation(&mut handle, &target, &provenance)
 }
 
-fn run_targets(args: &TargetsArgs) -> Result<(), Error> {
-    run_targets_from_path(&args.config, args.pretty)
+fn run_targets_lint(args: &TargetsLintArgs, warnings: &mut WarningCollector) -> Result<(), Error> {
+    use imir::{explain_targets, lint_targets};
+
+    let contents =
+        fs::read_to_string(&args.config).map_err(|source| imir::io_error(&args.config, source))?;
+    let targets = explain_targets(&contents)?;
+    let findings = lint_targets(&targets, &args.allow);
+
+    info!(
+        "Linted {} targets: {} finding(s)",
+        targets.len(),
+        findings.len()
+    );
+    for finding in findings {
+        warnings.push(finding.to_string());
+    }
+
+    Ok(())
+}
+
+fn write_target_explanation<W: io::Write>(
+    writer: &mut W,
+    target: &RenderTarget,
+    provenance: &RenderTargetProvenance
+) -> Result<(), Error> {
+    let serialized = serde_json::to_value(target)?;
+    let rows: Vec<(&str, String, FieldProvenance)> = provenance
+        .fields()
+        .into_iter()
+        .map(|(field, origin)| {
+            let pointer = format!("/{}", field.replace('.', "/"));
+            let value = match serialized.pointer(&pointer) {
+                Some(serde_json::Value::String(text)) => text.clone(),
+                Some(other) => other.to_string(),
+                None => String::new()
+            };
+            (field, value, origin)
+        })
+        .collect();
+
+    let field_width = rows
+        .iter()
+        .map(|(field, ..)| field.len())
+        .max()
+        .unwrap_or(0);
+    let value_width = rows
+        .iter()
+        .map(|(_, value, _)| value.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    for (field, value, origin) in rows {
+        writeln!(
+            writer,
+            "{field:<field_width$}  {value:<value_width$}  {origin}"
+        )
+        .map_err(|e| Error::service(format!("failed to write explanation: {e}")))?;
+    }
+
+    Ok(())
 }
 
 fn run_targets_from_path(path: &Path, pretty: bool) -> Result<(), Error> {
@@ -513,72 +780,140 @@ fn run_open_source(args: &OpenSourceArgs) -> Result<(), Error> {
     Ok(())
 }
 
-fn run_legacy_targets(args: &LegacyTargetsArgs) -> Result<(), Error> {
+fn run_legacy_targets(
+    args: &LegacyTargetsArgs,
+    warnings: &mut WarningCollector
+) -> Result<(), Error> {
     let config = args
         .config
         .as_deref()
         .ok_or_else(|| Error::validation("missing required --config <PATH> argument"))?;
 
+    warnings.push(
+        "invoking imir without a subcommand is deprecated; use `imir targets --config <PATH>`"
+    );
+
     run_targets_from_path(config, args.pretty)
 }
 
-fn run_badge(args: BadgeArgs) -> Result<(), Error> {
+async fn run_badge(args: BadgeArgs, warnings: &mut WarningCollector) -> Result<(), Error> {
     match args.command {
-        BadgeCommand::Generate(arguments) => run_badge_generate(&arguments),
-        BadgeCommand::GenerateAll(arguments) => run_badge_generate_all(&arguments)
+        BadgeCommand::Generate(arguments) => run_badge_generate(&arguments, warnings).await,
+        BadgeCommand::GenerateAll(arguments) => run_badge_generate_all(&arguments, warnings).await
     }
 }
 
-fn run_badge_generate(args: &BadgeGenerateArgs) -> Result<(), Error> {
-    let document = load_targets(&args.config)?;
+async fn load_badge_targets(
+    config: &Path,
+    avatars: &AvatarCacheArgs
+) -> Result<TargetsDocument, Error> {
+    use imir::{cache_remote_avatars, resolve_local_avatars, retry::RetryConfig};
+    use octocrab::Octocrab;
+
+    let mut document = load_targets(config)?;
+    if let Some(base_dir) = config.parent() {
+        resolve_local_avatars(&mut document.targets, base_dir);
+    }
+
+    if avatars.fetch_avatars {
+        let octocrab = Octocrab::builder()
+            .build()
+            .map_err(|e| Error::service(format!("failed to initialize HTTP client: {e}")))?;
+        let downloaded = cache_remote_avatars(
+            &octocrab,
+            &mut document.targets,
+            &avatars.avatar_cache,
+            &RetryConfig::default()
+        )
+        .await?;
+        info!(
+            "Downloaded {downloaded} avatar(s) into {}",
+            avatars.avatar_cache.display()
+        );
+    }
+
+    Ok(document)
+}
+
+async fn run_badge_generate(
+    args: &BadgeGenerateArgs,
+    warnings: &mut WarningCollector
+) -> Result<(), Error> {
+    let document = load_badge_targets(&args.config, &args.avatars).await?;
     let target = document
         .targets
         .iter()
         .find(|candidate| candidate.slug == args.target)
         .ok_or_else(|| Error::validation(format!("target '{}' was not found", args.target)))?;
 
+    warn_skipped_avatars([target], warnings);
     generate_badge_assets(target, &args.output)?;
 
     Ok(())
 }
 
-fn run_badge_generate_all(args: &BadgeGenerateAllArgs) -> Result<(), Error> {
+async fn run_badge_generate_all(
+    args: &BadgeGenerateAllArgs,
+    warnings: &mut WarningCollector
+) -> Result<(), Error> {
     use rayon::prelude::*;
     use tracing::{debug, info};
 
-    let document = load_targets(&args.config)?;
+    let document = load_badge_targets(&args.config, &args.avatars).await?;
+    warn_skipped_avatars(&document.targets, warnings);
     let output_dir = &args.output;
+    let action = if args.validate_only {
+        "validate"
+    } else {
+        "generate"
+    };
 
     info!(
-        "Generating {} badge assets in parallel",
+        "Processing {} badge assets in parallel ({action})",
         document.targets.len()
     );
 
-    let failed: Vec<String> = document
+    let failed: Vec<(&str, String)> = document
         .targets
         .par_iter()
         .filter_map(|target| {
-            debug!("Generating badge for {}", target.slug);
-            match generate_badge_assets(target, output_dir) {
-                Ok(_) => None,
-                Err(e) => {
-                    eprintln!("Failed to generate badge for {}: {e}", target.slug);
-                    Some(format!("{}: {e}", target.slug))
-                }
-            }
+            debug!("Processing badge for {}", target.slug);
+            let result = if args.validate_only {
+                validate_badge(target)
+            } else {
+                generate_badge_assets(target, output_dir).map(drop)
+            };
+            result.err().map(|e| {
+                let detail = match e {
+                    Error::Validation {
+                        message
+                    }
+                    | Error::SvgParse {
+                        message
+                    } => message,
+                    other => other.to_string()
+                };
+                (target.slug.as_str(), detail)
+            })
         })
         .collect();
 
     if !failed.is_empty() {
+        let slugs: Vec<&str> = failed.iter().map(|(slug, _)| *slug).collect();
+        let details: Vec<String> = failed
+            .iter()
+            .map(|(slug, detail)| format!("{slug}: {detail}"))
+            .collect();
         return Err(Error::validation(format!(
-            "{} badge(s) failed to generate: {}",
+            "{} badge(s) failed to {action} [{}]: {}",
             failed.len(),
-            failed.join("; ")
+            slugs.join(", "),
+            details.join("; ")
         )));
     }
 
     info!(
-        "Successfully generated {} badge assets",
+        "Successfully processed {} badge assets",
         document.targets.len()
     );
     Ok(())
@@ -594,27 +929,99 @@ async fn run_discover(args: DiscoverArgs) -> Result<(), Error> {
         "Starting repository discovery using source: {}",
         args.source
     );
-    let repositories = discover_repositories(&args.token, &args.source, &config).await?;
-    info!("Discovered {} repositories", repositories.len());
+    let started = std::time::Instant::now();
+    let (discovered, repositories) = if let Some(size) = args.sample {
+        let seed = args.seed.unwrap_or_else(time_seed);
+        info!("Sampling {size} repositories with seed {seed}");
+        let pages = discovery_pages(&args.token, &args.source, &config)?;
+        let sample = sample_repository_pages(pages, size, seed)
+            .await
+            .map_err(|e| Error::service(e.to_string()))?;
+        (sample.discovered, sample.repositories)
+    } else {
+        let repositories = discover_repositories(&args.token, &args.source, &config).await?;
+        (repositories.len(), repositories)
+    };
+    let elapsed = started.elapsed();
+
+    write_discovery(
+        &args,
+        discovered,
+        &repositories,
+        elapsed,
+        &mut io::stdout().lock(),
+        &mut io::stderr().lock()
+    )
+}
 
-    let stdout = io::stdout();
-    let mut handle = stdout.lock();
+/// Writes discovered repositories to `stdout` and, with `--plain`, the
+/// summary line to `stderr`.
+///
+/// `discovered` counts every repository found, which exceeds
+/// `repositories.len()` when the output is a `--sample`.
+fn write_discovery<O: io::Write, E: io::Write>(
+    args: &DiscoverArgs,
+    discovered: usize,
+    repositories: &[imir::DiscoveredRepository],
+    elapsed: std::time::Duration,
+    stdout: &mut O,
+    stderr: &mut E
+) -> Result<(), Error> {
+    use imir::DiscoverySummary;
+
+    info!("Discovered {discovered} repositories");
 
     match args.format.as_str() {
         "json" => {
-            serde_json::to_writer_pretty(&mut handle, &repositories)?;
+            serde_json::to_writer_pretty(&mut *stdout, repositories)?;
         }
         "yaml" => {
-            serde_yaml::to_writer(&mut handle, &repositories)?;
+            serde_yaml::to_writer(&mut *stdout, repositories)?;
         }
         format => {
             return Err(Error::validation(format!("unsupported format: {format}")));
         }
     }
 
+    if args.plain {
+        let summary = DiscoverySummary {
+            discovered,
+            source: &args.source,
+            elapsed
+        };
+        writeln!(stderr, "{summary}")
+            .map_err(|e| Error::service(format!("failed to write discovery summary: {e}")))?;
+    }
+
     Ok(())
 }
 
+/// Derives a sampling seed from the current time.
+fn time_seed() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+}
+
+/// Streams discovery pages for `source`, chaining both sources for `all`.
+fn discovery_pages(
+    token: &str,
+    source: &str,
+    config: &DiscoveryConfig
+) -> Result<BoxStream<'static, Result<Vec<imir::DiscoveredRepository>, AppError>>, Error> {
+    let pages = |config| {
+        stargazer_repository_pages(token, config).map_err(|e| Error::service(e.to_string()))
+    };
+
+    match source {
+        "badge" | "stargazers" => Ok(pages(config)?.boxed()),
+        "all" => Ok(pages(config)?.chain(pages(config)?).boxed()),
+        source => Err(Error::validation(format!(
+            "unsupported source: {source}. Use: badge, stargazers, or all"
+        )))
+    }
+}
+
 async fn discover_repositories(
     token: &str,
     source: &str,
@@ -693,8 +1100,9 @@ async fn run_sync(args: SyncArgs) -> Result<(), Error> {
     Ok(())
 }
 
-fn run_readme(args: &ReadmeArgs) -> Result<(), Error> {
-    use imir::update_readme;
+async fn run_readme(args: &ReadmeArgs, warnings: &mut WarningCollector) -> Result<(), Error> {
+    use imir::{check_metrics_links, metrics_links, retry::RetryConfig, update_readme};
+    use octocrab::Octocrab;
 
     info!("Loading targets from {}", args.config.display());
     let document = load_targets(&args.config)?;
@@ -703,17 +1111,30 @@ fn run_readme(args: &ReadmeArgs) -> Result<(), Error> {
     update_readme(&args.readme, &document).map_err(|e| Error::service(e.to_string()))?;
 
     println!("README updated successfully at {}", args.readme.display());
+
+    if args.check_links {
+        let octocrab = Octocrab::builder()
+            .build()
+            .map_err(|e| Error::service(format!("failed to initialize HTTP client: {e}")))?;
+        let urls = metrics_links(&document);
+
+        info!("Checking {} metrics links", urls.len());
+        for link in check_metrics_links(&octocrab, &urls, &RetryConfig::default()).await {
+            warnings.push(format!("broken metrics link: {link}"));
+        }
+    }
+
     Ok(())
 }
 
 async fn run_contributors(args: ContributorsArgs) -> Result<(), Error> {
-    use imir::{fetch_contributor_activity, retry::RetryConfig};
+    use imir::{
+        fetch_contributor_activity, fetch_contributor_weekly_activity, retry::RetryConfig
+    };
     use octocrab::Octocrab;
 
-    info!(
-        "Fetching contributor activity for {}/{}",
-        args.owner, args.repo
-    );
+    let (owner, repo) = args.owner_repo()?;
+    info!("Fetching contributor activity for {owner}/{repo}");
 
     let octocrab = Octocrab::builder()
         .personal_token(args.token.clone())
@@ -721,8 +1142,17 @@ async fn run_contributors(args: ContributorsArgs) -> Result<(), Error> {
         .map_err(|e| Error::service(format!("failed to initialize GitHub client: {e}")))?;
 
     let retry_config = RetryConfig::default();
-    let contributors =
-        fetch_contributor_activity(&octocrab, &args.owner, &args.repo, &retry_config).await?;
+
+    if args.graph {
+        let series =
+            fetch_contributor_weekly_activity(&octocrab, owner, repo, &retry_config).await?;
+
+        let stdout = io::stdout();
+        let mut handle = stdout.lock();
+        return write_contributor_graph(&mut handle, &series);
+    }
+
+    let contributors = fetch_contributor_activity(&octocrab, owner, repo, &retry_config).await?;
 
     let json = serde_json::to_string_pretty(&contributors)
         .map_err(|e| Error::service(format!("failed to serialize contributors: {e}")))?;
@@ -732,6 +1162,77 @@ async fn run_contributors(args: ContributorsArgs) -> Result<(), Error> {
     Ok(())
 }
 
+async fn run_summary(args: SummaryArgs, warnings: &mut WarningCollector) -> Result<(), Error> {
+    use imir::{retry::RetryConfig, summarize_contributors};
+    use octocrab::Octocrab;
+
+    let document = load_targets(&args.config)?;
+    let repositories: Vec<(String, String)> = document
+        .targets
+        .into_iter()
+        .filter_map(|target| target.repository.map(|repo| (target.owner, repo)))
+        .collect();
+
+    info!(
+        "Summarizing contributors for {} repositories (concurrency {})",
+        repositories.len(),
+        args.concurrency
+    );
+
+    let octocrab = Octocrab::builder()
+        .personal_token(args.token.clone())
+        .build()
+        .map_err(|e| Error::service(format!("failed to initialize GitHub client: {e}")))?;
+
+    let summary = summarize_contributors(
+        &octocrab,
+        &repositories,
+        args.concurrency.get(),
+        &RetryConfig::default()
+    )
+    .await;
+
+    for failure in &summary.failures {
+        warnings.push(format!(
+            "skipped {}/{}: {}",
+            failure.owner, failure.repository, failure.error
+        ));
+    }
+
+    let json = serde_json::to_string_pretty(&summary)
+        .map_err(|e| Error::service(format!("failed to serialize summary: {e}")))?;
+
+    println!("{json}");
+
+    Ok(())
+}
+
+fn write_contributor_graph<W: io::Write>(
+    writer: &mut W,
+    series: &[ContributorWeeklyActivity]
+) -> Result<(), Error> {
+    let top = &series[..series.len().min(CONTRIBUTOR_GRAPH_LIMIT)];
+    let login_width = top.iter().map(|c| c.login.len()).max().unwrap_or(0);
+    let graph_width = top
+        .iter()
+        .map(|c| c.weekly_commits.len())
+        .max()
+        .unwrap_or(0);
+
+    for contributor in top {
+        writeln!(
+            writer,
+            "{:<login_width$}  {:<graph_width$}  {} commits",
+            contributor.login,
+            format_sparkline(&contributor.weekly_commits),
+            contributor.total_commits()
+        )
+        .map_err(|e| Error::service(format!("failed to write contributor graph: {e}")))?;
+    }
+
+    Ok(())
+}
+
 fn run_slugs(args: &SlugsArgs) -> Result<(), Error> {
     info!(
         "Detecting impacted slugs: base={}, head={}, files={:?}",
@@ -815,20 +1316,43 @@ fn run_git(args: GitArgs) -> Result<(), Error> {
     }
 }
 
-fn run_gh(args: GhArgs) -> Result<(), Error> {
+async fn run_gh(args: GhArgs) -> Result<(), Error> {
     match args.command {
         GhCommand::PrCreate(pr_args) => {
-            info!(
-                "Creating PR: repo={}, head={}, base={}",
-                pr_args.repo, pr_args.head, pr_args.base
-            );
-
             let label_refs: Vec<&str> = pr_args
                 .labels
                 .iter()
                 .map(std::string::String::as_str)
                 .collect();
 
+            if pr_args.probe {
+                info!(
+                    "Probing PR: repo={}, head={}, base={}",
+                    pr_args.repo, pr_args.head, pr_args.base
+                );
+
+                let report = gh_pr_probe(
+                    &pr_args.repo,
+                    &pr_args.head,
+                    &pr_args.base,
+                    &label_refs,
+                    &pr_args.token
+                )
+                .await?;
+
+                let json = serde_json::to_string(&report)
+                    .map_err(|e| Error::service(format!("failed to serialize result: {e}")))?;
+
+                println!("{json}");
+
+                return Ok(());
+            }
+
+            info!(
+                "Creating PR: repo={}, head={}, base={}",
+                pr_args.repo, pr_args.head, pr_args.base
+            );
+
             let result = gh_pr_create(
                 &pr_args.repo,
                 &pr_args.head,
@@ -837,7 +1361,8 @@ fn run_gh(args: GhArgs) -> Result<(), Error> {
                 &pr_args.body,
                 &label_refs,
                 &pr_args.token
-            )?;
+            )
+            .await?;
 
             let json = serde_json::to_string(&result)
                 .map_err(|e| Error::service(format!("failed to serialize result: {e}")))?;
@@ -857,15 +1382,21 @@ fn run_render(args: RenderArgs) -> Result<(), Error> {
                 profile_args.target_user
             );
 
-            let result = normalize_profile_inputs(
-                &profile_args.target_user,
-                profile_args.branch_name.as_deref(),
-                profile_args.target_path.as_deref(),
-                profile_args.temp_artifact.as_deref(),
-                profile_args.time_zone.as_deref(),
-                profile_args.display_name.as_deref(),
-                profile_args.include_private.as_deref()
-            )?;
+            let policy = match profile_args.config.as_deref() {
+                Some(path) => load_privacy_policy(path)?,
+                None => PrivacyPolicy::default()
+            };
+
+            let result = normalize_profile_inputs(&ProfileRequest {
+                target_user:     &profile_args.target_user,
+                branch_name:     profile_args.branch_name.as_deref(),
+                target_path:     profile_args.target_path.as_deref(),
+                temp_artifact:   profile_args.temp_artifact.as_deref(),
+                time_zone:       profile_args.time_zone.as_deref(),
+                display_name:    profile_args.display_name.as_deref(),
+                include_private: profile_args.include_private.as_deref(),
+                policy:          &policy
+            })?;
 
             let json = serde_json::to_string(&result)
                 .map_err(|e| Error::service(format!("failed to serialize result: {e}")))?;
@@ -920,14 +1451,16 @@ fn run_svg(args: SvgArgs) -> Result<(), Error> {
 
 #[cfg(test)]
 mod tests {
-    use std::{fs, io::Cursor, path::Path};
+    use std::{fs, io::Cursor, path::Path, time::Duration};
 
     use clap::Parser;
-    use imir::TargetsDocument;
+    use imir::{DiscoveredRepository, TargetsDocument, WarningCollector, sample_repository_pages};
     use tempfile::tempdir;
 
     use super::{
-        Cli, Command, LegacyTargetsArgs, run_badge, run_legacy_targets, write_targets_document
+        Cli, Command, LegacyTargetsArgs, TargetsCommand, run_badge, run_cli, run_legacy_targets,
+        write_contributor_graph, write_discovery, write_target_explanation,
+        write_targets_document
     };
 
     #[test]
@@ -943,7 +1476,9 @@ mod tests {
     #[test]
     fn legacy_targets_require_config_path() {
         let args = LegacyTargetsArgs::default();
-        let error = run_legacy_targets(&args).expect_err("expected validation error");
+        let mut warnings = WarningCollector::new();
+        let error =
+            run_legacy_targets(&args, &mut warnings).expect_err("expected validation error");
 
         match error {
             imir::Error::Validation {
@@ -1001,8 +1536,8 @@ mod tests {
         assert_eq!(output, "{\"targets\":[]}");
     }
 
-    #[test]
-    fn badge_generate_writes_assets() {
+    #[tokio::test]
+    async fn badge_generate_writes_assets() {
         let temp = tempdir().expect("failed to create tempdir");
         let config_path = temp.path().join("targets.yaml");
         let output_dir = temp.path().join("artifacts");
@@ -1033,7 +1568,9 @@ targets:
             other => panic!("unexpected command variant: {other:?}")
         };
 
-        run_badge(args).expect("badge generation failed");
+        run_badge(args, &mut WarningCollector::new())
+            .await
+            .expect("badge generation failed");
 
         let svg_path = output_dir.join("example-repo.svg");
         let manifest_path = output_dir.join("example-repo.json");
@@ -1041,8 +1578,52 @@ targets:
         assert!(manifest_path.exists());
     }
 
-    #[test]
-    fn badge_generate_all_writes_assets_for_every_target() {
+    #[tokio::test]
+    async fn badge_generate_all_resolves_avatars_against_config_directory() {
+        let temp = tempdir().expect("failed to create tempdir");
+        let config_path = temp.path().join("targets.yaml");
+        let output_dir = temp.path().join("artifacts");
+        fs::create_dir(temp.path().join("avatars")).expect("failed to create avatars dir");
+        fs::write(temp.path().join("avatars/me.png"), b"png").expect("failed to write avatar");
+        let yaml = r"
+targets:
+  - owner: local
+    type: profile
+    avatar: avatars/me.png
+  - owner: remote
+    type: profile
+    avatar: https://example.com/remote.png
+";
+        fs::write(&config_path, yaml).expect("failed to write config");
+
+        let cli = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "badge",
+            "generate-all",
+            "--config",
+            config_path.to_str().expect("utf8"),
+            "--output",
+            output_dir.to_str().expect("utf8")
+        ])
+        .expect("failed to parse badge command");
+        let Some(Command::Badge(args)) = cli.command else {
+            panic!("expected badge command")
+        };
+
+        let mut warnings = WarningCollector::new();
+        run_badge(args, &mut warnings)
+            .await
+            .expect("badge generation failed");
+
+        let svg =
+            fs::read_to_string(output_dir.join("local-profile.svg")).expect("missing local badge");
+        assert!(svg.contains("href=\"data:image/png;base64,cG5n\""));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings.messages()[0].starts_with("skipping remote avatar for remote-profile;"));
+    }
+
+    #[tokio::test]
+    async fn badge_generate_all_writes_assets_for_every_target() {
         let temp = tempdir().expect("failed to create tempdir");
         let config_path = temp.path().join("targets.yaml");
         let output_dir = temp.path().join("artifacts");
@@ -1075,7 +1656,9 @@ targets:
             other => panic!("unexpected command variant: {other:?}")
         };
 
-        run_badge(args).expect("batch badge generation failed");
+        run_badge(args, &mut WarningCollector::new())
+            .await
+            .expect("batch badge generation failed");
 
         for slug in ["example-alpha", "example-beta"] {
             assert!(output_dir.join(format!("{slug}.svg")).exists());
@@ -1083,8 +1666,68 @@ targets:
         }
     }
 
-    #[test]
-    fn badge_generate_all_reports_failed_slugs_in_error() {
+    #[tokio::test]
+    async fn badge_generate_all_validate_only_lists_overflowing_slugs() {
+        let temp = tempdir().expect("failed to create tempdir");
+        let config_path = temp.path().join("targets.yaml");
+        let output_dir = temp.path().join("artifacts");
+        let yaml = r"
+targets:
+  - owner: example
+    repository: alpha
+    type: open_source
+  - owner: example
+    repository: an-exceptionally-long-repository-name
+    type: open_source
+    slug: example-long
+  - owner: example
+    repository: another-exceptionally-long-repository-name
+    type: open_source
+    slug: example-longer
+";
+        fs::write(&config_path, yaml).expect("failed to write config");
+
+        let cli = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "badge",
+            "generate-all",
+            "--config",
+            config_path.to_str().expect("utf8"),
+            "--output",
+            output_dir.to_str().expect("utf8"),
+            "--validate-only"
+        ])
+        .expect("failed to parse badge generate-all command");
+
+        let args = match cli.command.expect("missing command") {
+            Command::Badge(arguments) => arguments,
+            other => panic!("unexpected command variant: {other:?}")
+        };
+
+        let error = run_badge(args, &mut WarningCollector::new())
+            .await
+            .expect_err("expected validation failure");
+        match error {
+            imir::Error::Validation {
+                message
+            } => {
+                assert!(
+                    message.starts_with(
+                        "2 badge(s) failed to validate [example-long, example-longer]: \
+                         example-long: label 'example/an-exceptionally-long-repository-name'"
+                    ),
+                    "unexpected message: {message}"
+                );
+                assert!(!message.contains("alpha"));
+                assert!(!message.contains("invalid configuration"));
+            }
+            other => panic!("unexpected error variant: {other:?}")
+        }
+        assert!(!output_dir.exists(), "validation must not write artifacts");
+    }
+
+    #[tokio::test]
+    async fn badge_generate_all_reports_failed_slugs_in_error() {
         let temp = tempdir().expect("failed to create tempdir");
         let config_path = temp.path().join("targets.yaml");
         let blocker_path = temp.path().join("blocker");
@@ -1115,23 +1758,27 @@ targets:
             other => panic!("unexpected command variant: {other:?}")
         };
 
-        let error = run_badge(args).expect_err("expected batch failure");
+        let error = run_badge(args, &mut WarningCollector::new())
+            .await
+            .expect_err("expected batch failure");
         match error {
             imir::Error::Validation {
                 message
             } => {
                 assert!(
-                    message.contains("example-alpha"),
-                    "error must name the failing slug, got: {message}"
+                    message.starts_with(
+                        "1 badge(s) failed to generate [example-alpha]: example-alpha: failed to \
+                         write badge artifact"
+                    ),
+                    "unexpected message: {message}"
                 );
-                assert!(message.contains("1 badge(s) failed to generate"));
             }
             other => panic!("unexpected error variant: {other:?}")
         }
     }
 
-    #[test]
-    fn badge_generate_reports_missing_target() {
+    #[tokio::test]
+    async fn badge_generate_reports_missing_target() {
         let temp = tempdir().expect("failed to create tempdir");
         let config_path = temp.path().join("targets.yaml");
         let yaml = r"
@@ -1159,7 +1806,9 @@ targets:
             other => panic!("unexpected command variant: {other:?}")
         };
 
-        let error = run_badge(args).expect_err("expected missing target error");
+        let error = run_badge(args, &mut WarningCollector::new())
+            .await
+            .expect_err("expected missing target error");
         match error {
             imir::Error::Validation {
                 message
@@ -1194,13 +1843,179 @@ targets:
 
         match cli.command.expect("missing command") {
             Command::Targets(args) => {
-                assert_eq!(args.config, config_path);
+                assert_eq!(args.config, Some(config_path));
                 assert!(!args.pretty);
             }
             other => panic!("unexpected command variant: {other:?}")
         }
     }
 
+    #[test]
+    fn targets_explain_parses_slug_and_config() {
+        let cli = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "targets",
+            "explain",
+            "metrics",
+            "--config",
+            "config.yaml"
+        ])
+        .expect("failed to parse targets explain");
+
+        let Some(Command::Targets(args)) = cli.command else {
+            panic!("unexpected command variant")
+        };
+        let Some(TargetsCommand::Explain(explain)) = args.command else {
+            panic!("missing explain subcommand")
+        };
+        assert_eq!(explain.slug, "metrics");
+        assert_eq!(explain.config, Path::new("config.yaml"));
+    }
+
+    #[test]
+    fn targets_lint_reports_findings_as_warnings() {
+        let temp = tempdir().expect("failed to create tempdir");
+        let config_path = temp.path().join("targets.yaml");
+        let yaml = "\
+targets:
+  - owner: an-organization-with-a-long-name
+    repo: and-an-even-longer-repository-name
+    slug: long
+    type: open_source
+  - owner: octocat
+    repo: pale
+    type: open_source
+    badge:
+      color: '#eeeeee'
+  - owner: octocat
+    repo: metrics
+    type: profile
+";
+        fs::write(&config_path, yaml).expect("failed to write config");
+
+        let cli = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "targets",
+            "lint",
+            "--config",
+            config_path.to_str().expect("utf8"),
+            "--allow",
+            "long-label"
+        ])
+        .expect("failed to parse targets lint");
+        let Some(Command::Targets(args)) = cli.command else {
+            panic!("unexpected command variant")
+        };
+
+        let mut warnings = WarningCollector::new();
+        super::run_targets(&args, &mut warnings).expect("lint succeeds");
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings.messages(),
+            [
+                "pale: badge.color #eeeeee has a 1.16:1 contrast ratio against white text \
+                 (minimum 4.5:1) [low-contrast]",
+                "octocat-profile: profile targets ignore repository-only fields: repository \
+                 [profile-repo-fields]"
+            ]
+        );
+    }
+
+    #[test]
+    fn targets_lint_rejects_unknown_lint_id() {
+        let result = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "targets",
+            "lint",
+            "--config",
+            "config.yaml",
+            "--allow",
+            "bogus"
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn targets_without_subcommand_still_requires_config() {
+        let result = Cli::try_parse_from([env!("CARGO_PKG_NAME"), "targets"]);
+        assert!(result.is_err(), "targets must require --config");
+    }
+
+    #[test]
+    fn write_target_explanation_lists_values_with_provenance() {
+        let yaml = r"
+defaults:
+  owner: ourorg
+targets:
+  - repo: metrics
+    type: open_source
+    branch_name: custom/metrics
+";
+        let (target, provenance) = imir::explain_target(yaml, "metrics").expect("explain");
+
+        let mut buffer = Cursor::new(Vec::new());
+        write_target_explanation(&mut buffer, &target, &provenance)
+            .expect("failed to write explanation");
+        let output = String::from_utf8(buffer.into_inner()).expect("invalid UTF-8");
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+
+        assert_eq!(lines.len(), 17);
+        assert_eq!(
+            lines[0],
+            "slug                        metrics                   derived from entry"
+        );
+        assert_eq!(
+            lines[1],
+            "owner                       ourorg                    document default"
+        );
+        assert_eq!(
+            lines[2],
+            "repository                  metrics                   override"
+        );
+        assert_eq!(
+            lines[3],
+            "branch_name                 custom/metrics            override"
+        );
+        assert_eq!(
+            lines[11],
+            "badge.widget.columns        1                         built-in default"
+        );
+    }
+
+    #[test]
+    fn summary_command_parses_concurrency() {
+        let cli = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "summary",
+            "--config",
+            "targets.yaml",
+            "--token",
+            "t",
+            "--concurrency",
+            "8"
+        ])
+        .expect("failed to parse summary command");
+
+        let Some(Command::Summary(args)) = cli.command else {
+            panic!("unexpected command variant")
+        };
+        assert_eq!(args.concurrency.get(), 8);
+
+        let rejected = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "summary",
+            "--config",
+            "targets.yaml",
+            "--token",
+            "t",
+            "--concurrency",
+            "0"
+        ]);
+        assert!(rejected.is_err(), "zero concurrency must be rejected");
+    }
+
     #[test]
     fn targets_command_reports_missing_file() {
         let temp = tempdir().expect("failed to create tempdir");
@@ -1219,7 +2034,7 @@ targets:
             other => panic!("unexpected command variant: {other:?}")
         };
 
-        let result = super::run_targets(&args);
+        let result = super::run_targets(&args, &mut WarningCollector::new());
         assert!(result.is_err(), "should fail for missing file");
     }
 
@@ -1242,7 +2057,7 @@ targets:
             other => panic!("unexpected command variant: {other:?}")
         };
 
-        let result = super::run_targets(&args);
+        let result = super::run_targets(&args, &mut WarningCollector::new());
         assert!(result.is_err(), "should fail for invalid YAML");
     }
 
@@ -1369,4 +2184,370 @@ targets:
             }
         }
     }
+
+    fn write_minimal_config(directory: &Path) -> std::path::PathBuf {
+        let config_path = directory.join("targets.yaml");
+        let yaml = r"
+targets:
+  - owner: example
+    repository: repo
+    type: open_source
+";
+        fs::write(&config_path, yaml).expect("failed to write config");
+        config_path
+    }
+
+    #[test]
+    fn legacy_targets_emit_deprecation_warning() {
+        let temp = tempdir().expect("failed to create tempdir");
+        let args = LegacyTargetsArgs {
+            config: Some(write_minimal_config(temp.path())),
+            pretty: false
+        };
+
+        let mut warnings = WarningCollector::new();
+        run_legacy_targets(&args, &mut warnings).expect("legacy invocation failed");
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings.messages()[0].contains("deprecated"));
+    }
+
+    #[test]
+    fn cli_parses_global_fail_on_warnings_flag() {
+        let cli = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "targets",
+            "--config",
+            "config.yaml",
+            "--fail-on-warnings"
+        ])
+        .expect("failed to parse CLI");
+
+        assert!(cli.fail_on_warnings);
+    }
+
+    #[tokio::test]
+    async fn fail_on_warnings_turns_deprecation_warning_into_error() {
+        let temp = tempdir().expect("failed to create tempdir");
+        let config_path = write_minimal_config(temp.path());
+        let config = config_path.to_str().expect("utf8");
+
+        let lenient = Cli::try_parse_from([env!("CARGO_PKG_NAME"), "--config", config])
+            .expect("failed to parse CLI");
+        run_cli(lenient)
+            .await
+            .expect("warnings must not fail without the flag");
+
+        let strict = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "--fail-on-warnings",
+            "--config",
+            config
+        ])
+        .expect("failed to parse CLI");
+        let error = run_cli(strict)
+            .await
+            .expect_err("expected warnings to fail the command");
+
+        match error {
+            imir::Error::Validation {
+                message
+            } => {
+                assert!(message.starts_with("1 warning(s) treated as errors"));
+                assert!(message.contains("deprecated"));
+            }
+            other => panic!("unexpected error variant: {other:?}")
+        }
+    }
+
+    #[test]
+    fn contributors_command_parses_graph_flag() {
+        let cli = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "contributors",
+            "--owner",
+            "octo",
+            "--repo",
+            "cat",
+            "--token",
+            "test_token",
+            "--graph"
+        ])
+        .expect("failed to parse contributors command");
+
+        match cli.command.expect("missing command") {
+            Command::Contributors(args) => {
+                assert!(args.graph);
+                assert_eq!(args.owner_repo().expect("owner/repo"), ("octo", "cat"));
+            }
+            other => panic!("unexpected command variant: {other:?}")
+        }
+    }
+
+    #[test]
+    fn contributors_command_accepts_positional_slug() {
+        let cli = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "contributors",
+            "octo/cat",
+            "--token",
+            "test_token"
+        ])
+        .expect("failed to parse contributors command");
+
+        match cli.command.expect("missing command") {
+            Command::Contributors(args) => {
+                assert_eq!(args.owner_repo().expect("owner/repo"), ("octo", "cat"));
+                assert!(args.owner.is_none());
+                assert!(args.repo.is_none());
+            }
+            other => panic!("unexpected command variant: {other:?}")
+        }
+    }
+
+    #[test]
+    fn contributors_command_rejects_malformed_slug() {
+        for slug in ["octocat", "octo/", "/cat", "octo/cat/extra"] {
+            let error = Cli::try_parse_from([
+                env!("CARGO_PKG_NAME"),
+                "contributors",
+                slug,
+                "--token",
+                "test_token"
+            ])
+            .expect_err("malformed slug should be rejected");
+
+            assert_eq!(error.kind(), clap::error::ErrorKind::ValueValidation);
+            assert!(
+                error
+                    .to_string()
+                    .contains(&format!("expected OWNER/REPO, got '{slug}'"))
+            );
+        }
+    }
+
+    #[test]
+    fn contributors_command_requires_a_repository() {
+        for arguments in [
+            &["contributors", "--token", "t"][..],
+            &["contributors", "--owner", "octo", "--token", "t"],
+            &[
+                "contributors",
+                "octo/cat",
+                "--owner",
+                "octo",
+                "--token",
+                "t"
+            ]
+        ] {
+            let parsed = Cli::try_parse_from(
+                std::iter::once(env!("CARGO_PKG_NAME")).chain(arguments.iter().copied())
+            );
+            assert!(parsed.is_err(), "expected {arguments:?} to be rejected");
+        }
+    }
+
+    #[test]
+    fn contributor_graph_aligns_rows_and_limits_output() {
+        let mut series = vec![
+            imir::ContributorWeeklyActivity {
+                login:          "alice".to_owned(),
+                weekly_commits: vec![0, 2, 4, 8]
+            },
+            imir::ContributorWeeklyActivity {
+                login:          "bob".to_owned(),
+                weekly_commits: vec![1, 0, 0, 0]
+            },
+        ];
+        series.extend((0..20).map(|index| imir::ContributorWeeklyActivity {
+            login:          format!("user{index}"),
+            weekly_commits: vec![0, 0, 0, 1]
+        }));
+
+        let mut buffer = Cursor::new(Vec::new());
+        write_contributor_graph(&mut buffer, &series).expect("failed to write graph");
+
+        let output = String::from_utf8(buffer.into_inner()).expect("invalid UTF-8");
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), super::CONTRIBUTOR_GRAPH_LIMIT);
+        assert_eq!(lines[0], "alice  ▁▃▅█  14 commits");
+        assert_eq!(lines[1], "bob    █▁▁▁  1 commits");
+    }
+
+    #[test]
+    fn gh_pr_create_parses_probe_flag() {
+        let cli = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "gh",
+            "pr-create",
+            "--repo",
+            "owner/repo",
+            "--head",
+            "feature",
+            "--base",
+            "main",
+            "--title",
+            "title",
+            "--body",
+            "body",
+            "--token",
+            "token",
+            "--probe"
+        ])
+        .expect("failed to parse gh pr-create command");
+
+        match cli.command.expect("missing command") {
+            Command::Gh(args) => {
+                let super::GhCommand::PrCreate(pr_args) = args.command;
+                assert!(pr_args.probe);
+            }
+            other => panic!("unexpected command variant: {other:?}")
+        }
+    }
+
+    #[test]
+    fn discover_plain_writes_summary_line_to_stderr() {
+        let cli = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "discover",
+            "--token",
+            "test-token",
+            "--source",
+            "all",
+            "--plain"
+        ])
+        .expect("failed to parse discover command");
+        let args = match cli.command.expect("missing command") {
+            Command::Discover(arguments) => arguments,
+            other => panic!("unexpected command variant: {other:?}")
+        };
+
+        let discovered = ["alpha", "beta"]
+            .map(|repository| DiscoveredRepository {
+                owner:      "octocat".to_owned(),
+                repository: repository.to_owned()
+            })
+            .to_vec();
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        write_discovery(
+            &args,
+            discovered.len(),
+            &discovered,
+            Duration::ZERO,
+            &mut stdout,
+            &mut stderr
+        )
+        .expect("discovery succeeds");
+
+        let parsed: serde_json::Value = serde_json::from_slice(&stdout).expect("valid JSON");
+        assert_eq!(parsed.as_array().map(Vec::len), Some(2));
+
+        let summary = String::from_utf8(stderr).expect("invalid UTF-8");
+        let line = summary
+            .strip_suffix('\n')
+            .expect("summary ends with newline");
+        assert!(
+            !line.contains('\n'),
+            "expected a single line, got {summary:?}"
+        );
+        let elapsed = line
+            .strip_prefix("discovered=2 source=all elapsed_ms=")
+            .unwrap_or_else(|| panic!("unexpected summary line {line:?}"));
+        elapsed.parse::<u128>().expect("elapsed_ms is an integer");
+    }
+
+    #[test]
+    fn discover_without_plain_keeps_stderr_empty() {
+        let cli =
+            Cli::try_parse_from([env!("CARGO_PKG_NAME"), "discover", "--token", "test-token"])
+                .expect("failed to parse discover command");
+        let args = match cli.command.expect("missing command") {
+            Command::Discover(arguments) => arguments,
+            other => panic!("unexpected command variant: {other:?}")
+        };
+
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        write_discovery(&args, 0, &[], Duration::ZERO, &mut stdout, &mut stderr)
+            .expect("discovery succeeds");
+
+        assert_eq!(stdout, b"[]");
+        assert!(stderr.is_empty());
+    }
+
+    #[tokio::test]
+    async fn discover_sample_is_deterministic_with_seed() {
+        let cli = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "discover",
+            "--token",
+            "test-token",
+            "--sample",
+            "3",
+            "--seed",
+            "42",
+            "--plain"
+        ])
+        .expect("failed to parse discover command");
+        let args = match cli.command.expect("missing command") {
+            Command::Discover(arguments) => arguments,
+            other => panic!("unexpected command variant: {other:?}")
+        };
+
+        let source = || {
+            futures::stream::iter((0..4).map(|page| {
+                Ok((0..5)
+                    .map(|index| DiscoveredRepository {
+                        owner:      "octocat".to_owned(),
+                        repository: format!("repo-{}", page * 5 + index)
+                    })
+                    .collect())
+            }))
+        };
+
+        let mut outputs = Vec::new();
+        for _ in 0..2 {
+            let seed = args.seed.expect("seed is set");
+            let size = args.sample.expect("sample is set");
+            let sample = sample_repository_pages(source(), size, seed)
+                .await
+                .expect("sampling succeeds");
+
+            let mut stdout = Vec::new();
+            let mut stderr = Vec::new();
+            write_discovery(
+                &args,
+                sample.discovered,
+                &sample.repositories,
+                Duration::ZERO,
+                &mut stdout,
+                &mut stderr
+            )
+            .expect("discovery succeeds");
+
+            let summary = String::from_utf8(stderr).expect("invalid UTF-8");
+            assert!(summary.starts_with("discovered=20 source=all "));
+            outputs.push(stdout);
+        }
+
+        let sample: Vec<DiscoveredRepository> =
+            serde_json::from_slice(&outputs[0]).expect("valid JSON");
+        assert_eq!(sample.len(), 3);
+        assert_eq!(outputs[0], outputs[1]);
+    }
+
+    #[test]
+    fn discover_seed_requires_sample() {
+        let result = Cli::try_parse_from([
+            env!("CARGO_PKG_NAME"),
+            "discover",
+            "--token",
+            "test-token",
+            "--seed",
+            "42"
+        ]);
+
+        assert!(result.is_err());
+    }
 }
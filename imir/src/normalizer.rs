@@ -10,15 +10,25 @@
 //! artifacts, and branch names. The resulting structures are ready for
 //! serialization into workflow matrix inputs.
 
-use std::{collections::HashSet, fs, path::Path};
+use std::{
+    cell::Cell,
+    collections::{BTreeMap, HashSet},
+    fmt, fs,
+    path::Path
+};
 
-use serde::Serialize;
+use serde::{
+    Deserializer, Serialize,
+    de::{self, DeserializeSeed, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor}
+};
 
 use crate::{
     config::{
-        BadgeOptions, BadgeStyle, BadgeWidgetAlignment, TargetConfig, TargetEntry, TargetKind
+        BadgeOptions, BadgeStyle, BadgeWidgetAlignment, TargetConfig, TargetDefaults, TargetEntry,
+        TargetKind
     },
-    error::{self, Error}
+    error::{self, Error},
+    render::PrivacyPolicy
 };
 
 /// Prefix applied to branch names when no custom override is supplied.
@@ -36,6 +46,52 @@ const DEFAULT_BADGE_STYLE: BadgeStyle = BadgeStyle::Classic;
 const DEFAULT_BADGE_COLUMNS: u8 = 1;
 const DEFAULT_BADGE_ALIGNMENT: BadgeWidgetAlignment = BadgeWidgetAlignment::Start;
 const DEFAULT_BADGE_BORDER_RADIUS: u8 = 4;
+const DEFAULT_BADGE_SHOW_ICON: bool = false;
+/// Largest configuration document accepted by default, in bytes.
+const DEFAULT_MAX_DOCUMENT_BYTES: usize = 1024 * 1024;
+/// Largest number of YAML nodes accepted by default after alias expansion.
+const DEFAULT_MAX_EXPANDED_NODES: usize = 100_000;
+
+/// Limits enforced while decoding YAML target configurations.
+///
+/// Anchors and aliases let a small document expand into an enormous tree
+/// ("billion laughs"). Every node produced by alias expansion counts towards
+/// [`max_expanded_nodes`](Self::max_expanded_nodes), so pathological
+/// documents are rejected before they are deserialized.
+///
+/// # Examples
+///
+/// ```
+/// use imir::{ParseLimits, parse_targets_with_limits};
+///
+/// let limits = ParseLimits {
+///     max_expanded_nodes: 16,
+///     ..ParseLimits::default()
+/// };
+/// let yaml = "
+/// targets:
+///   - owner: octocat
+///     type: profile
+/// ";
+/// assert!(parse_targets_with_limits(yaml, &limits).is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// Largest accepted document size in bytes (default: 1 MiB).
+    pub max_document_bytes: usize,
+    /// Largest accepted number of YAML nodes after alias expansion
+    /// (default: 100 000).
+    pub max_expanded_nodes: usize
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_document_bytes: DEFAULT_MAX_DOCUMENT_BYTES,
+            max_expanded_nodes: DEFAULT_MAX_EXPANDED_NODES
+        }
+    }
+}
 
 /// Normalized representation of a metrics target used by automation workflows.
 #[derive(Debug, Serialize, Clone, PartialEq, Eq)]
@@ -64,16 +120,57 @@ pub struct RenderTarget {
     /// repositories.
     pub include_private:     bool,
     /// Normalized badge descriptor associated with the target.
-    pub badge:               BadgeDescriptor
+    pub badge:               BadgeDescriptor,
+    /// Avatar source embedded in profile badges.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar:              Option<String>
+}
+
+/// Borrowed slug identifying a [`RenderTarget`] inside maps and sets.
+///
+/// Slugs are unique within a [`TargetsDocument`], so the key can stand in for
+/// the whole target without cloning it.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use imir::parse_targets;
+///
+/// let yaml = r"
+/// targets:
+///   - owner: octocat
+///     repo: metrics
+///     type: open_source
+/// ";
+/// let document = parse_targets(yaml).expect("valid configuration");
+/// let keys: HashSet<_> = document.targets.iter().map(|target| target.key()).collect();
+/// assert!(keys.contains(&imir::RenderTargetKey("metrics")));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RenderTargetKey<'a>(pub &'a str);
+
+impl RenderTarget {
+    /// Returns the slug-based key for this target.
+    #[must_use]
+    pub fn key(&self) -> RenderTargetKey<'_> {
+        RenderTargetKey(&self.slug)
+    }
 }
 
 /// Normalized badge descriptor emitted alongside render targets.
 #[derive(Debug, Serialize, Clone, PartialEq, Eq)]
 pub struct BadgeDescriptor {
     /// Visual style preset selected for the badge.
-    pub style:  BadgeStyle,
+    pub style:     BadgeStyle,
     /// Normalized widget options that control layout.
-    pub widget: BadgeWidgetDescriptor
+    pub widget:    BadgeWidgetDescriptor,
+    /// Whether the target kind icon is drawn in the badge corner.
+    pub show_icon: bool,
+    /// Background color override in lowercase `#rrggbb` form.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color:     Option<String>
 }
 
 /// Normalized widget parameters derived from configuration overrides.
@@ -94,6 +191,142 @@ pub struct TargetsDocument {
     pub targets: Vec<RenderTarget>
 }
 
+impl TargetsDocument {
+    /// Consumes the document and indexes its targets by slug.
+    ///
+    /// Normalization guarantees unique slugs, so no target is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use imir::parse_targets;
+    ///
+    /// let yaml = r"
+    /// targets:
+    ///   - owner: octocat
+    ///     repo: metrics
+    ///     type: open_source
+    ///   - owner: octocat
+    ///     type: profile
+    /// ";
+    /// let by_slug = parse_targets(yaml)
+    ///     .expect("valid configuration")
+    ///     .into_by_slug();
+    /// assert_eq!(by_slug["metrics"].owner, "octocat");
+    /// assert!(by_slug.contains_key("octocat-profile"));
+    /// ```
+    #[must_use]
+    pub fn into_by_slug(self) -> BTreeMap<String, RenderTarget> {
+        self.targets
+            .into_iter()
+            .map(|target| (target.slug.clone(), target))
+            .collect()
+    }
+}
+
+/// Origin of a normalized field value.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldProvenance {
+    /// Value set explicitly on the configuration entry.
+    Override,
+    /// Value inherited from the document `defaults` section.
+    DocumentDefault,
+    /// Value computed from the target slug.
+    DerivedFromSlug,
+    /// Value computed from the entry owner or repository.
+    DerivedFromEntry,
+    /// Value supplied by the normalizer when nothing else applies.
+    BuiltinDefault
+}
+
+impl FieldProvenance {
+    const fn from_override(is_set: bool, fallback: Self) -> Self {
+        if is_set { Self::Override } else { fallback }
+    }
+}
+
+impl fmt::Display for FieldProvenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Override => "override",
+            Self::DocumentDefault => "document default",
+            Self::DerivedFromSlug => "derived from slug",
+            Self::DerivedFromEntry => "derived from entry",
+            Self::BuiltinDefault => "built-in default"
+        })
+    }
+}
+
+/// Provenance of every defaultable field in a [`RenderTarget`].
+///
+/// Fields follow [`RenderTarget`] with the badge descriptor flattened, so each
+/// entry records where the corresponding normalized value came from.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct RenderTargetProvenance {
+    /// Origin of [`RenderTarget::slug`].
+    pub slug:                FieldProvenance,
+    /// Origin of [`RenderTarget::owner`].
+    pub owner:               FieldProvenance,
+    /// Origin of [`RenderTarget::repository`].
+    pub repository:          FieldProvenance,
+    /// Origin of [`RenderTarget::branch_name`].
+    pub branch_name:         FieldProvenance,
+    /// Origin of [`RenderTarget::target_path`].
+    pub target_path:         FieldProvenance,
+    /// Origin of [`RenderTarget::temp_artifact`].
+    pub temp_artifact:       FieldProvenance,
+    /// Origin of [`RenderTarget::time_zone`].
+    pub time_zone:           FieldProvenance,
+    /// Origin of [`RenderTarget::display_name`].
+    pub display_name:        FieldProvenance,
+    /// Origin of [`RenderTarget::contributors_branch`].
+    pub contributors_branch: FieldProvenance,
+    /// Origin of [`RenderTarget::include_private`].
+    pub include_private:     FieldProvenance,
+    /// Origin of [`BadgeDescriptor::style`].
+    pub badge_style:         FieldProvenance,
+    /// Origin of [`BadgeWidgetDescriptor::columns`].
+    pub badge_columns:       FieldProvenance,
+    /// Origin of [`BadgeWidgetDescriptor::alignment`].
+    pub badge_alignment:     FieldProvenance,
+    /// Origin of [`BadgeWidgetDescriptor::border_radius`].
+    pub badge_border_radius: FieldProvenance,
+    /// Origin of [`BadgeDescriptor::show_icon`].
+    pub badge_show_icon:     FieldProvenance,
+    /// Origin of [`BadgeDescriptor::color`].
+    pub badge_color:         FieldProvenance,
+    /// Origin of [`RenderTarget::avatar`].
+    pub avatar:              FieldProvenance
+}
+
+impl RenderTargetProvenance {
+    /// Returns every tracked field as a dotted path into the serialized
+    /// [`RenderTarget`] alongside its provenance, in declaration order.
+    #[must_use]
+    pub fn fields(&self) -> [(&'static str, FieldProvenance); 17] {
+        [
+            ("slug", self.slug),
+            ("owner", self.owner),
+            ("repository", self.repository),
+            ("branch_name", self.branch_name),
+            ("target_path", self.target_path),
+            ("temp_artifact", self.temp_artifact),
+            ("time_zone", self.time_zone),
+            ("display_name", self.display_name),
+            ("contributors_branch", self.contributors_branch),
+            ("include_private", self.include_private),
+            ("badge.style", self.badge_style),
+            ("badge.widget.columns", self.badge_columns),
+            ("badge.widget.alignment", self.badge_alignment),
+            ("badge.widget.border_radius", self.badge_border_radius),
+            ("badge.show_icon", self.badge_show_icon),
+            ("badge.color", self.badge_color),
+            ("avatar", self.avatar)
+        ]
+    }
+}
+
 /// Loads targets from the provided YAML configuration file path.
 ///
 /// # Errors
@@ -101,8 +334,37 @@ pub struct TargetsDocument {
 /// Returns an [`Error`] when the file cannot be read, the YAML cannot be
 /// deserialized, or the configuration violates invariants during normalization.
 pub fn load_targets(path: &Path) -> Result<TargetsDocument, Error> {
+    load_targets_with_limits(path, &ParseLimits::default())
+}
+
+/// Loads targets from the YAML configuration file at `path` while enforcing
+/// `limits`.
+///
+/// # Errors
+///
+/// Returns an [`Error`] under the same conditions as [`load_targets`];
+/// documents exceeding `limits` are rejected with [`Error::Parse`].
+pub fn load_targets_with_limits(
+    path: &Path,
+    limits: &ParseLimits
+) -> Result<TargetsDocument, Error> {
     let contents = fs::read_to_string(path).map_err(|source| error::io_error(path, source))?;
-    parse_targets(&contents)
+    parse_targets_with_limits(&contents, limits)
+}
+
+/// Loads the profile privacy policy declared in the YAML configuration file.
+///
+/// Falls back to [`PrivacyPolicy::default`] when the document has no `privacy`
+/// section.
+///
+/// # Errors
+///
+/// Returns [`Error::Io`](Error::Io) when the file cannot be read and
+/// [`Error::Parse`](Error::Parse) when the YAML cannot be decoded.
+pub fn load_privacy_policy(path: &Path) -> Result<PrivacyPolicy, Error> {
+    let contents = fs::read_to_string(path).map_err(|source| error::io_error(path, source))?;
+    let config = decode_config(&contents, &ParseLimits::default())?;
+    Ok(config.privacy.unwrap_or_default())
 }
 
 /// Parses targets from the provided YAML document string.
@@ -113,19 +375,180 @@ pub fn load_targets(path: &Path) -> Result<TargetsDocument, Error> {
 /// # Errors
 ///
 /// Propagates [`Error::Parse`](Error::Parse) when the YAML cannot be decoded
-/// and [`Error::Validation`](Error::Validation) when required entries are
-/// missing.
+/// or exceeds the default [`ParseLimits`] and
+/// [`Error::Validation`](Error::Validation) when required entries are missing.
 pub fn parse_targets(contents: &str) -> Result<TargetsDocument, Error> {
-    let config: TargetConfig = serde_yaml::from_str(contents)?;
-    if config.targets.is_empty() {
-        return Err(Error::validation(
-            "configuration must include at least one target"
-        ));
-    }
+    parse_targets_with_limits(contents, &ParseLimits::default())
+}
 
+/// Parses targets from the provided YAML document string while enforcing
+/// `limits`.
+///
+/// # Errors
+///
+/// Returns [`Error::Parse`](Error::Parse) when the document is larger than
+/// [`ParseLimits::max_document_bytes`] or expands into more than
+/// [`ParseLimits::max_expanded_nodes`] nodes, in addition to every error
+/// reported by [`parse_targets`].
+pub fn parse_targets_with_limits(
+    contents: &str,
+    limits: &ParseLimits
+) -> Result<TargetsDocument, Error> {
+    let mut config = decode_targets(contents, limits)?;
+    config.apply_defaults();
     normalize_targets(&config.targets)
 }
 
+/// Normalizes the document and explains how the target with `slug` was
+/// derived.
+///
+/// # Errors
+///
+/// Propagates every error reported by [`parse_targets`] and returns
+/// [`Error::Validation`](Error::Validation) when no target matches `slug`.
+///
+/// # Examples
+///
+/// ```
+/// use imir::{FieldProvenance, explain_target};
+///
+/// let yaml = r"
+/// defaults:
+///   owner: octocat
+/// targets:
+///   - repo: metrics
+///     type: open_source
+///     branch_name: custom/branch
+/// ";
+/// let (target, provenance) = explain_target(yaml, "metrics").expect("valid configuration");
+/// assert_eq!(target.branch_name, "custom/branch");
+/// assert_eq!(provenance.branch_name, FieldProvenance::Override);
+/// assert_eq!(provenance.owner, FieldProvenance::DocumentDefault);
+/// ```
+pub fn explain_target(
+    contents: &str,
+    slug: &str
+) -> Result<(RenderTarget, RenderTargetProvenance), Error> {
+    explain_targets(contents)?
+        .into_iter()
+        .find(|(target, _)| target.slug == slug)
+        .ok_or_else(|| Error::validation(format!("no target with slug '{slug}'")))
+}
+
+/// Normalizes the document and pairs every target with its provenance.
+///
+/// # Errors
+///
+/// Propagates every error reported by [`parse_targets`].
+pub fn explain_targets(
+    contents: &str
+) -> Result<Vec<(RenderTarget, RenderTargetProvenance)>, Error> {
+    let mut config = decode_targets(contents, &ParseLimits::default())?;
+    let provenance: Vec<RenderTargetProvenance> = config
+        .targets
+        .iter()
+        .map(|entry| entry_provenance(entry, config.defaults.as_ref()))
+        .collect();
+
+    config.apply_defaults();
+    let document = normalize_targets(&config.targets)?;
+
+    Ok(document.targets.into_iter().zip(provenance).collect())
+}
+
+/// Records where each field of the normalized entry will come from.
+///
+/// Must be called before [`TargetConfig::apply_defaults`] so inherited values
+/// can be told apart from overrides.
+fn entry_provenance(
+    entry: &TargetEntry,
+    defaults: Option<&TargetDefaults>
+) -> RenderTargetProvenance {
+    let owner = if entry.owner.is_some() {
+        FieldProvenance::Override
+    } else if defaults.is_some_and(|defaults| defaults.owner.is_some()) {
+        FieldProvenance::DocumentDefault
+    } else {
+        FieldProvenance::BuiltinDefault
+    };
+
+    let display_name_fallback = match entry.target_type {
+        TargetKind::Profile => FieldProvenance::BuiltinDefault,
+        TargetKind::OpenSource | TargetKind::PrivateProject => FieldProvenance::DerivedFromEntry
+    };
+    let time_zone_set = entry
+        .time_zone
+        .as_deref()
+        .is_some_and(|value| !value.trim().is_empty());
+    let badge = entry.badge.as_ref();
+    let widget = badge.and_then(|options| options.widget.as_ref());
+
+    RenderTargetProvenance {
+        slug: FieldProvenance::from_override(
+            entry.slug.is_some(),
+            FieldProvenance::DerivedFromEntry
+        ),
+        owner,
+        repository: FieldProvenance::from_override(
+            entry.repository.is_some(),
+            FieldProvenance::BuiltinDefault
+        ),
+        branch_name: FieldProvenance::from_override(
+            entry.branch_name.is_some(),
+            FieldProvenance::DerivedFromSlug
+        ),
+        target_path: FieldProvenance::from_override(
+            entry.target_path.is_some(),
+            FieldProvenance::DerivedFromSlug
+        ),
+        temp_artifact: FieldProvenance::from_override(
+            entry.temp_artifact.is_some(),
+            FieldProvenance::DerivedFromSlug
+        ),
+        time_zone: FieldProvenance::from_override(time_zone_set, FieldProvenance::BuiltinDefault),
+        display_name: FieldProvenance::from_override(
+            entry.display_name.is_some(),
+            display_name_fallback
+        ),
+        contributors_branch: FieldProvenance::from_override(
+            entry.contributors_branch.is_some(),
+            FieldProvenance::BuiltinDefault
+        ),
+        include_private: FieldProvenance::from_override(
+            entry.include_private.is_some(),
+            FieldProvenance::BuiltinDefault
+        ),
+        badge_style: FieldProvenance::from_override(
+            badge.is_some_and(|options| options.style.is_some()),
+            FieldProvenance::BuiltinDefault
+        ),
+        badge_columns: FieldProvenance::from_override(
+            widget.is_some_and(|widget| widget.columns.is_some()),
+            FieldProvenance::BuiltinDefault
+        ),
+        badge_alignment: FieldProvenance::from_override(
+            widget.is_some_and(|widget| widget.alignment.is_some()),
+            FieldProvenance::BuiltinDefault
+        ),
+        badge_border_radius: FieldProvenance::from_override(
+            widget.is_some_and(|widget| widget.border_radius.is_some()),
+            FieldProvenance::BuiltinDefault
+        ),
+        badge_show_icon: FieldProvenance::from_override(
+            badge.is_some_and(|options| options.show_icon.is_some()),
+            FieldProvenance::BuiltinDefault
+        ),
+        badge_color: FieldProvenance::from_override(
+            badge.is_some_and(|options| options.color.is_some()),
+            FieldProvenance::BuiltinDefault
+        ),
+        avatar: FieldProvenance::from_override(
+            entry.avatar.is_some(),
+            FieldProvenance::BuiltinDefault
+        )
+    }
+}
+
 /// Normalizes raw configuration entries into a deduplicated document.
 ///
 /// # Errors
@@ -182,7 +605,11 @@ fn normalize_targets(entries: &[TargetEntry]) -> Result<TargetsDocument, Error>
 /// Returns [`Error::Validation`](Error::Validation) when required fields are
 /// missing or contain disallowed characters.
 fn normalize_entry(entry: &TargetEntry) -> Result<RenderTarget, Error> {
-    let owner = normalize_identifier(&entry.owner, "owner")?;
+    let owner = entry
+        .owner
+        .as_deref()
+        .ok_or_else(|| Error::validation("owner is required when defaults.owner is not set"))?;
+    let owner = normalize_identifier(owner, "owner")?;
 
     let repository = match entry.target_type {
         TargetKind::Profile => None,
@@ -238,6 +665,7 @@ fn normalize_entry(entry: &TargetEntry) -> Result<RenderTarget, Error> {
         .include_private
         .unwrap_or_else(|| default_include_private(&owner, entry.target_type));
     let badge = normalize_badge(entry.badge.as_ref())?;
+    let avatar = normalize_avatar(entry.avatar.as_deref(), entry.target_type)?;
 
     Ok(RenderTarget {
         slug,
@@ -251,10 +679,23 @@ fn normalize_entry(entry: &TargetEntry) -> Result<RenderTarget, Error> {
         display_name,
         contributors_branch,
         include_private,
-        badge
+        badge,
+        avatar
     })
 }
 
+fn normalize_avatar(avatar: Option<&str>, kind: TargetKind) -> Result<Option<String>, Error> {
+    let Some(avatar) = avatar else {
+        return Ok(None);
+    };
+    if kind != TargetKind::Profile {
+        return Err(Error::validation(
+            "avatar is only supported for profile targets"
+        ));
+    }
+    normalize_path_like(avatar, "avatar").map(Some)
+}
+
 fn default_include_private(owner: &str, kind: TargetKind) -> bool {
     matches!(kind, TargetKind::Profile,) && owner == "RAprogramm"
 }
@@ -277,6 +718,13 @@ fn normalize_badge(badge: Option<&BadgeOptions>) -> Result<BadgeDescriptor, Erro
 
     let columns = validate_badge_columns(columns_value)?;
     let border_radius = validate_badge_border_radius(border_radius_value)?;
+    let show_icon = badge
+        .and_then(|options| options.show_icon)
+        .unwrap_or(DEFAULT_BADGE_SHOW_ICON);
+    let color = badge
+        .and_then(|options| options.color.as_deref())
+        .map(normalize_badge_color)
+        .transpose()?;
 
     Ok(BadgeDescriptor {
         style,
@@ -284,10 +732,23 @@ fn normalize_badge(badge: Option<&BadgeOptions>) -> Result<BadgeDescriptor, Erro
             columns,
             alignment,
             border_radius
-        }
+        },
+        show_icon,
+        color
     })
 }
 
+fn normalize_badge_color(value: &str) -> Result<String, Error> {
+    let hex = value.trim();
+    let digits = hex.strip_prefix('#').unwrap_or(hex);
+    if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(Error::validation(format!(
+            "badge.color must be a #rrggbb hex color, got '{value}'"
+        )));
+    }
+    Ok(format!("#{}", digits.to_ascii_lowercase()))
+}
+
 fn validate_badge_columns(value: u8) -> Result<u8, Error> {
     if value == 0 || value > 4 {
         return Err(Error::validation(
@@ -341,13 +802,147 @@ fn normalize_path_like(input: &str, field: &str) -> Result<String, Error> {
     Ok(trimmed.to_owned())
 }
 
+/// Decodes the configuration after checking the document against `limits`.
+pub(crate) fn decode_config(contents: &str, limits: &ParseLimits) -> Result<TargetConfig, Error> {
+    if contents.len() > limits.max_document_bytes {
+        return Err(parse_error(format!(
+            "configuration is {} bytes, exceeding the {} byte limit",
+            contents.len(),
+            limits.max_document_bytes
+        )));
+    }
+
+    let counter = NodeCounter {
+        limit: limits.max_expanded_nodes,
+        count: &Cell::new(0)
+    };
+    counter.deserialize(serde_yaml::Deserializer::from_str(contents))?;
+
+    Ok(serde_yaml::from_str(contents)?)
+}
+
+/// Decodes a targets document and rejects one without any target.
+fn decode_targets(contents: &str, limits: &ParseLimits) -> Result<TargetConfig, Error> {
+    let config = decode_config(contents, limits)?;
+    if config.targets.is_empty() {
+        return Err(Error::validation(
+            "configuration must include at least one target"
+        ));
+    }
+    Ok(config)
+}
+
+fn parse_error(message: String) -> Error {
+    Error::Parse {
+        source: de::Error::custom(message)
+    }
+}
+
+/// Walks a YAML document, counting nodes as aliases are expanded, and aborts
+/// once `limit` is exceeded without materializing the tree.
+#[derive(Clone, Copy)]
+struct NodeCounter<'a> {
+    limit: usize,
+    count: &'a Cell<usize>
+}
+
+impl NodeCounter<'_> {
+    fn visit<E: de::Error>(self) -> Result<(), E> {
+        let count = self.count.get() + 1;
+        self.count.set(count);
+        if count > self.limit {
+            return Err(E::custom(format!(
+                "document expands to more than {} YAML nodes; reduce anchor/alias nesting",
+                self.limit
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for NodeCounter<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for NodeCounter<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("any YAML value")
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<(), E> {
+        self.visit()
+    }
+
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<(), E> {
+        self.visit()
+    }
+
+    fn visit_i128<E: de::Error>(self, _: i128) -> Result<(), E> {
+        self.visit()
+    }
+
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<(), E> {
+        self.visit()
+    }
+
+    fn visit_u128<E: de::Error>(self, _: u128) -> Result<(), E> {
+        self.visit()
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<(), E> {
+        self.visit()
+    }
+
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<(), E> {
+        self.visit()
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        self.visit()
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<(), E> {
+        self.visit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        self.deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        self.visit()?;
+        while seq.next_element_seed(self)?.is_some() {}
+        Ok(())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        self.visit()?;
+        while map.next_key_seed(self)?.is_some() {
+            map.next_value_seed(self)?;
+        }
+        Ok(())
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<(), A::Error> {
+        let ((), variant) = data.variant_seed(self)?;
+        variant.newtype_variant_seed(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::io::Write;
+    use std::{collections::HashMap, io::Write};
 
     use super::{
-        Error, load_targets, normalize_entry, normalize_identifier, normalize_path_like,
-        normalize_targets, parse_targets
+        Error, FieldProvenance, ParseLimits, RenderTargetKey, explain_target, explain_targets,
+        load_targets, normalize_entry, normalize_identifier, normalize_path_like,
+        normalize_targets, parse_targets, parse_targets_with_limits
     };
     use crate::config::{
         BadgeOptions, BadgeStyle, BadgeWidgetAlignment, BadgeWidgetOptions, TargetEntry,
@@ -356,7 +951,7 @@ mod tests {
 
     fn repository_entry() -> TargetEntry {
         TargetEntry {
-            owner:               "RAprogramm".to_owned(),
+            owner:               Some("RAprogramm".to_owned()),
             repository:          Some("metrics".to_owned()),
             target_type:         TargetKind::OpenSource,
             slug:                None,
@@ -367,13 +962,14 @@ mod tests {
             time_zone:           None,
             display_name:        None,
             include_private:     None,
-            badge:               None
+            badge:               None,
+            avatar:              None
         }
     }
 
     fn profile_entry(owner: &str) -> TargetEntry {
         TargetEntry {
-            owner:               owner.to_owned(),
+            owner:               Some(owner.to_owned()),
             repository:          None,
             target_type:         TargetKind::Profile,
             slug:                None,
@@ -384,7 +980,8 @@ mod tests {
             time_zone:           None,
             display_name:        None,
             include_private:     None,
-            badge:               None
+            badge:               None,
+            avatar:              None
         }
     }
 
@@ -404,6 +1001,7 @@ mod tests {
         assert_eq!(target.badge.widget.columns, 1);
         assert_eq!(target.badge.widget.alignment, BadgeWidgetAlignment::Start);
         assert_eq!(target.badge.widget.border_radius, 4);
+        assert!(!target.badge.show_icon);
     }
 
     #[test]
@@ -436,7 +1034,7 @@ mod tests {
     #[test]
     fn normalizes_infra_metrics_insight_renderer_target() {
         let entry = TargetEntry {
-            owner:               "RAprogramm".to_owned(),
+            owner:               Some("RAprogramm".to_owned()),
             repository:          Some("infra-metrics-insight-renderer".to_owned()),
             target_type:         TargetKind::OpenSource,
             slug:                Some("infra-metrics-insight-renderer".to_owned()),
@@ -447,7 +1045,8 @@ mod tests {
             time_zone:           None,
             display_name:        Some("Infra Metrics Insight Renderer".to_owned()),
             include_private:     None,
-            badge:               None
+            badge:               None,
+            avatar:              None
         };
 
         let target = normalize_entry(&entry).expect("expected target to normalize");
@@ -473,7 +1072,7 @@ mod tests {
     #[test]
     fn normalizes_profile_entry_with_overrides() {
         let entry = TargetEntry {
-            owner:               " Octocat ".to_owned(),
+            owner:               Some(" Octocat ".to_owned()),
             repository:          None,
             target_type:         TargetKind::Profile,
             slug:                Some(" Custom.Profile ".to_owned()),
@@ -484,7 +1083,8 @@ mod tests {
             time_zone:           Some("  UTC  ".to_owned()),
             display_name:        Some("  Profile Name  ".to_owned()),
             include_private:     None,
-            badge:               None
+            badge:               None,
+            avatar:              None
         };
 
         let target = normalize_entry(&entry).expect("expected overrides to be honored");
@@ -502,12 +1102,14 @@ mod tests {
     fn normalizes_badge_overrides() {
         let mut entry = repository_entry();
         entry.badge = Some(BadgeOptions {
-            style:  Some(BadgeStyle::FlatSquare),
-            widget: Some(BadgeWidgetOptions {
+            style:     Some(BadgeStyle::FlatSquare),
+            widget:    Some(BadgeWidgetOptions {
                 columns:       Some(3),
                 alignment:     Some(BadgeWidgetAlignment::Center),
                 border_radius: Some(8)
-            })
+            }),
+            show_icon: None,
+            color:     None
         });
 
         let target = normalize_entry(&entry).expect("expected badge override to normalize");
@@ -521,12 +1123,14 @@ mod tests {
     fn normalize_entry_rejects_badge_columns_out_of_range() {
         let mut entry = repository_entry();
         entry.badge = Some(BadgeOptions {
-            style:  None,
-            widget: Some(BadgeWidgetOptions {
+            style:     None,
+            widget:    Some(BadgeWidgetOptions {
                 columns:       Some(0),
                 alignment:     None,
                 border_radius: None
-            })
+            }),
+            show_icon: None,
+            color:     None
         });
 
         let error = normalize_entry(&entry).expect_err("expected badge validation failure");
@@ -544,12 +1148,14 @@ mod tests {
     fn normalize_entry_rejects_badge_border_radius_out_of_range() {
         let mut entry = repository_entry();
         entry.badge = Some(BadgeOptions {
-            style:  Some(BadgeStyle::Flat),
-            widget: Some(BadgeWidgetOptions {
+            style:     Some(BadgeStyle::Flat),
+            widget:    Some(BadgeWidgetOptions {
                 columns:       None,
                 alignment:     None,
                 border_radius: Some(64)
-            })
+            }),
+            show_icon: None,
+            color:     None
         });
 
         let error = normalize_entry(&entry).expect_err("expected badge validation failure");
@@ -731,6 +1337,120 @@ mod tests {
         assert_eq!(badge.widget.border_radius, 6);
     }
 
+    #[test]
+    fn parse_targets_inherits_default_owner() {
+        let yaml = r"
+            defaults:
+              owner: ourorg
+            targets:
+              - repo: metrics
+                type: open_source
+              - type: profile
+        ";
+
+        let document = parse_targets(yaml).expect("expected parse success");
+        assert_eq!(document.targets[0].owner, "ourorg");
+        assert_eq!(document.targets[1].owner, "ourorg");
+        assert_eq!(document.targets[1].slug, "ourorg-profile");
+    }
+
+    #[test]
+    fn parse_targets_entry_owner_overrides_default() {
+        let yaml = r"
+            defaults:
+              owner: ourorg
+            targets:
+              - owner: octocat
+                repo: metrics
+                type: open_source
+        ";
+
+        let document = parse_targets(yaml).expect("expected parse success");
+        assert_eq!(document.targets[0].owner, "octocat");
+    }
+
+    #[test]
+    fn parse_targets_validates_owner_after_defaults_merge() {
+        let missing = r"
+            targets:
+              - repo: metrics
+                type: open_source
+        ";
+        match parse_targets(missing).expect_err("expected missing owner failure") {
+            Error::Validation {
+                message
+            } => {
+                assert_eq!(message, "owner is required when defaults.owner is not set");
+            }
+            other => panic!("expected validation error, got {other:?}")
+        }
+
+        let blank_default = r"
+            defaults:
+              owner: '  '
+            targets:
+              - repo: metrics
+                type: open_source
+        ";
+        match parse_targets(blank_default).expect_err("expected blank owner failure") {
+            Error::Validation {
+                message
+            } => {
+                assert_eq!(message, "owner cannot be empty");
+            }
+            other => panic!("expected validation error, got {other:?}")
+        }
+    }
+
+    #[test]
+    fn parse_targets_enables_badge_icon() {
+        let yaml = r"
+            targets:
+              - owner: octocat
+                repo: metrics
+                type: open_source
+                badge:
+                  show_icon: true
+        ";
+
+        let document = parse_targets(yaml).expect("expected parse success");
+        assert!(document.targets[0].badge.show_icon);
+    }
+
+    #[test]
+    fn parse_targets_normalizes_badge_color() {
+        let yaml = r"
+            targets:
+              - owner: octocat
+                repo: metrics
+                type: open_source
+                badge:
+                  color: FFCC00
+        ";
+
+        let document = parse_targets(yaml).expect("expected parse success");
+        assert_eq!(document.targets[0].badge.color.as_deref(), Some("#ffcc00"));
+    }
+
+    #[test]
+    fn parse_targets_rejects_invalid_badge_color() {
+        let yaml = r"
+            targets:
+              - owner: octocat
+                repo: metrics
+                type: open_source
+                badge:
+                  color: red
+        ";
+
+        let error = parse_targets(yaml).expect_err("expected invalid color");
+        assert!(
+            error
+                .to_string()
+                .contains("badge.color must be a #rrggbb hex color")
+        );
+    }
+
     #[test]
     fn parse_targets_propagates_decode_errors() {
         let result = parse_targets("targets: invalid");
@@ -816,4 +1536,208 @@ mod tests {
         let error = load_targets(path).expect_err("expected io error");
         assert!(matches!(error, Error::Io { .. }));
     }
+
+    #[test]
+    fn explain_target_reports_provenance_for_mixed_entry() {
+        let yaml = r"
+defaults:
+  owner: ourorg
+targets:
+  - repo: api
+    type: open_source
+  - repo: metrics
+    type: open_source
+    target_path: dashboards/metrics.svg
+    time_zone: UTC
+    badge:
+      widget:
+        columns: 2
+";
+
+        let (target, provenance) = explain_target(yaml, "metrics").expect("explain succeeds");
+
+        assert_eq!(target.owner, "ourorg");
+        assert_eq!(target.target_path, "dashboards/metrics.svg");
+        assert_eq!(target.branch_name, "ci/metrics-refresh-metrics");
+
+        assert_eq!(provenance.owner, FieldProvenance::DocumentDefault);
+        assert_eq!(provenance.repository, FieldProvenance::Override);
+        assert_eq!(provenance.slug, FieldProvenance::DerivedFromEntry);
+        assert_eq!(provenance.target_path, FieldProvenance::Override);
+        assert_eq!(provenance.branch_name, FieldProvenance::DerivedFromSlug);
+        assert_eq!(provenance.temp_artifact, FieldProvenance::DerivedFromSlug);
+        assert_eq!(provenance.time_zone, FieldProvenance::Override);
+        assert_eq!(provenance.display_name, FieldProvenance::DerivedFromEntry);
+        assert_eq!(
+            provenance.contributors_branch,
+            FieldProvenance::BuiltinDefault
+        );
+        assert_eq!(provenance.badge_columns, FieldProvenance::Override);
+        assert_eq!(provenance.badge_alignment, FieldProvenance::BuiltinDefault);
+        assert_eq!(provenance.avatar, FieldProvenance::BuiltinDefault);
+    }
+
+    #[test]
+    fn explain_target_reports_avatar_override() {
+        let yaml = r"
+targets:
+  - owner: octocat
+    type: profile
+    avatar: avatars/octocat.png
+";
+
+        let (target, provenance) =
+            explain_target(yaml, "octocat-profile").expect("explain succeeds");
+
+        assert_eq!(target.avatar.as_deref(), Some("avatars/octocat.png"));
+        assert_eq!(provenance.avatar, FieldProvenance::Override);
+    }
+
+    #[test]
+    fn explain_target_rejects_unknown_slug() {
+        let yaml = r"
+targets:
+  - owner: octocat
+    repo: metrics
+    type: open_source
+";
+
+        match explain_target(yaml, "missing") {
+            Err(Error::Validation {
+                message
+            }) => assert_eq!(message, "no target with slug 'missing'"),
+            other => panic!("expected validation error, got {other:?}")
+        }
+    }
+
+    #[test]
+    fn explain_targets_rejects_empty_configuration() {
+        match explain_targets("targets: []") {
+            Err(Error::Validation {
+                message
+            }) => assert_eq!(message, "configuration must include at least one target"),
+            other => panic!("expected validation error, got {other:?}")
+        }
+        assert!(parse_targets("targets: []").is_err());
+    }
+
+    #[test]
+    fn into_by_slug_indexes_every_target() {
+        let yaml = r"
+targets:
+  - owner: octocat
+    repo: metrics
+    type: open_source
+  - owner: octocat
+    repo: dashboards
+    type: private_project
+  - owner: octocat
+    type: profile
+";
+        let document = parse_targets(yaml).expect("valid configuration");
+        let by_slug = document.clone().into_by_slug();
+
+        assert_eq!(
+            by_slug.keys().map(String::as_str).collect::<Vec<_>>(),
+            ["dashboards", "metrics", "octocat-profile"]
+        );
+        assert_eq!(by_slug.get("metrics"), document.targets.first());
+        assert_eq!(by_slug["octocat-profile"].kind, TargetKind::Profile);
+    }
+
+    #[test]
+    fn render_target_key_supports_hash_lookup() {
+        let yaml = r"
+targets:
+  - owner: octocat
+    repo: metrics
+    type: open_source
+  - owner: octocat
+    type: profile
+";
+        let document = parse_targets(yaml).expect("valid configuration");
+        let index: HashMap<RenderTargetKey<'_>, usize> = document
+            .targets
+            .iter()
+            .enumerate()
+            .map(|(position, target)| (target.key(), position))
+            .collect();
+
+        assert_eq!(index.get(&RenderTargetKey("metrics")), Some(&0));
+        assert_eq!(index.get(&RenderTargetKey("octocat-profile")), Some(&1));
+        assert!(!index.contains_key(&RenderTargetKey("missing")));
+    }
+
+    #[test]
+    fn parse_targets_expands_moderately_nested_anchors() {
+        let yaml = r"
+            widget: &widget
+              columns: 2
+              alignment: center
+            badge: &badge
+              style: classic
+              widget: *widget
+            targets:
+              - owner: octocat
+                repo: alpha
+                type: open_source
+                badge: *badge
+              - owner: octocat
+                repo: beta
+                type: open_source
+                badge: *badge
+        ";
+
+        let limits = ParseLimits {
+            max_expanded_nodes: 64,
+            ..ParseLimits::default()
+        };
+        let document = parse_targets_with_limits(yaml, &limits).expect("anchors expand");
+
+        assert_eq!(document.targets.len(), 2);
+        for target in &document.targets {
+            assert_eq!(target.badge.widget.columns, 2);
+            assert_eq!(target.badge.widget.alignment, BadgeWidgetAlignment::Center);
+        }
+    }
+
+    #[test]
+    fn parse_targets_rejects_alias_expansion_beyond_limit() {
+        let yaml = r"
+            a: &a [x, x, x, x, x, x, x, x, x, x]
+            b: &b [*a, *a, *a, *a, *a, *a, *a, *a, *a, *a]
+            c: &c [*b, *b, *b, *b, *b, *b, *b, *b, *b, *b]
+            d: &d [*c, *c, *c, *c, *c, *c, *c, *c, *c, *c]
+            e: &e [*d, *d, *d, *d, *d, *d, *d, *d, *d, *d]
+            targets:
+              - owner: octocat
+                type: profile
+        ";
+
+        let limits = ParseLimits {
+            max_expanded_nodes: 5_000,
+            ..ParseLimits::default()
+        };
+        let error = parse_targets_with_limits(yaml, &limits).expect_err("expansion rejected");
+
+        assert!(matches!(error, Error::Parse { .. }));
+        assert!(
+            error
+                .to_string()
+                .contains("document expands to more than 5000 YAML nodes"),
+            "unexpected error: {error}"
+        );
+    }
+
+    #[test]
+    fn parse_targets_rejects_oversized_documents() {
+        let limits = ParseLimits {
+            max_document_bytes: 8,
+            ..ParseLimits::default()
+        };
+        let error = parse_targets_with_limits("targets: []\n", &limits).expect_err("too large");
+
+        assert!(matches!(error, Error::Parse { .. }));
+        assert!(error.to_string().contains("exceeding the 8 byte limit"));
+    }
 }
@@ -7,18 +7,44 @@
 /// - Profile badges
 /// - Open-source repositories
 /// - Private repositories
-use std::{fmt::Write as _, fs, path::Path};
+use std::{
+    fmt::{self, Write as _},
+    fs,
+    path::Path
+};
 
+use http::{Method, StatusCode, request::Builder};
 use masterror::AppError;
+use octocrab::Octocrab;
 use tracing::{debug, info};
 
-use crate::{RenderTarget, TargetKind, TargetsDocument};
+use crate::{
+    RenderTarget, TargetKind, TargetsDocument,
+    retry::{RetryConfig, retry_with_backoff}
+};
 
 const OPEN_SOURCE_START_MARKER: &str = "<summary>Open-source repositories</summary>";
 const PRIVATE_START_MARKER: &str = "<summary>Private repositories</summary>";
 const PROFILE_START_MARKER: &str = "<summary>Profile badges</summary>";
 const UPDATE_MARKER: &str = "<!-- IMIR will update this table automatically -->";
 const DETAILS_END_MARKER: &str = "</details>";
+const METRICS_BASE_URL: &str =
+    "https://raw.githubusercontent.com/RAprogramm/infra-metrics-insight-renderer/main/metrics";
+
+/// Metrics link embedded in the README that did not resolve successfully.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokenLink {
+    /// URL that was checked.
+    pub url:    String,
+    /// Human-readable reason the link is considered broken.
+    pub reason: String
+}
+
+impl fmt::Display for BrokenLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.url, self.reason)
+    }
+}
 
 /// Updates README.md badge tables based on targets configuration.
 ///
@@ -113,6 +139,114 @@ pub fn update_readme(readme_path: &Path, document: &TargetsDocument) -> Result<(
     Ok(())
 }
 
+/// Returns the metrics badge URLs embedded in the README for every target.
+///
+/// URLs are returned in document order and match the `src` attributes written
+/// by [`update_readme`].
+///
+/// # Arguments
+///
+/// * `document` - Parsed targets configuration
+#[must_use]
+pub fn metrics_links(document: &TargetsDocument) -> Vec<String> {
+    document
+        .targets
+        .iter()
+        .map(|target| metrics_url(&target.slug))
+        .collect()
+}
+
+/// Issues a `HEAD` request to every URL and reports links that do not answer
+/// with `200 OK`.
+///
+/// Transport failures and server errors are retried according to
+/// `retry_config`; client errors such as `404 Not Found` are reported
+/// immediately.
+///
+/// # Arguments
+///
+/// * `octocrab` - HTTP client used to issue the requests
+/// * `urls` - Links to verify
+/// * `retry_config` - Retry configuration for transient failures
+///
+/// # Example
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// use imir::{check_metrics_links, load_targets, metrics_links, retry::RetryConfig};
+/// use octocrab::Octocrab;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let document = load_targets(Path::new("targets/targets.yaml"))?;
+/// let octocrab = Octocrab::builder().build()?;
+/// let broken = check_metrics_links(
+///     &octocrab,
+///     &metrics_links(&document),
+///     &RetryConfig::default()
+/// )
+/// .await;
+/// for link in broken {
+///     eprintln!("broken link: {link}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn check_metrics_links(
+    octocrab: &Octocrab,
+    urls: &[String],
+    retry_config: &RetryConfig
+) -> Vec<BrokenLink> {
+    let mut broken = Vec::new();
+
+    for url in urls {
+        debug!("Checking metrics link {url}");
+        let operation = format!("check link {url}");
+        let outcome =
+            retry_with_backoff(retry_config, &operation, || head_status(octocrab, url)).await;
+
+        let reason = match outcome {
+            Ok(StatusCode::OK) => continue,
+            Ok(status) => format!("HTTP {}", status.as_u16()),
+            Err(error) => error.to_string()
+        };
+        broken.push(BrokenLink {
+            url: url.clone(),
+            reason
+        });
+    }
+
+    info!(
+        "Checked {} metrics links, {} broken",
+        urls.len(),
+        broken.len()
+    );
+    broken
+}
+
+async fn head_status(octocrab: &Octocrab, url: &str) -> Result<StatusCode, AppError> {
+    let request = octocrab
+        .build_request(Builder::new().method(Method::HEAD).uri(url), None::<&()>)
+        .map_err(|e| AppError::validation(format!("invalid link {url}: {e}")))?;
+    let response = octocrab
+        .execute(request)
+        .await
+        .map_err(|e| AppError::service(format!("request to {url} failed: {e}")))?;
+
+    let status = response.status();
+    if status.is_server_error() {
+        return Err(AppError::service(format!(
+            "HTTP {} from {url}",
+            status.as_u16()
+        )));
+    }
+    Ok(status)
+}
+
+fn metrics_url(slug: &str) -> String {
+    format!("{METRICS_BASE_URL}/{slug}.svg")
+}
+
 fn replace_section(
     content: &str,
     start_marker: &str,
@@ -156,10 +290,7 @@ fn generate_repository_table(targets: &[&RenderTarget]) -> String {
     for target in targets {
         let repo_name = target.repository.as_ref().map_or("", |r| r.as_str());
         let full_name = format!("{}/{}", target.owner, repo_name);
-        let metrics_url = format!(
-            "https://raw.githubusercontent.com/RAprogramm/infra-metrics-insight-renderer/main/metrics/{}.svg",
-            target.slug
-        );
+        let metrics_url = metrics_url(&target.slug);
 
         let _ = write!(
             table,
@@ -186,10 +317,7 @@ fn generate_private_section(targets: &[&RenderTarget]) -> String {
     for target in targets {
         let repo_name = target.repository.as_ref().map_or("", |r| r.as_str());
         let full_name = format!("{}/{}", target.owner, repo_name);
-        let metrics_url = format!(
-            "https://raw.githubusercontent.com/RAprogramm/infra-metrics-insight-renderer/main/metrics/{}.svg",
-            target.slug
-        );
+        let metrics_url = metrics_url(&target.slug);
 
         let _ = write!(
             table,
@@ -214,10 +342,7 @@ fn generate_profile_table(targets: &[&RenderTarget]) -> String {
     );
 
     for target in targets {
-        let metrics_url = format!(
-            "https://raw.githubusercontent.com/RAprogramm/infra-metrics-insight-renderer/main/metrics/{}.svg",
-            target.slug
-        );
+        let metrics_url = metrics_url(&target.slug);
 
         let _ = write!(
             table,
@@ -269,13 +394,16 @@ mod tests {
             contributors_branch: "main".to_owned(),
             include_private: false,
             badge: BadgeDescriptor {
-                style:  BadgeStyle::Classic,
-                widget: BadgeWidgetDescriptor {
+                style:     BadgeStyle::Classic,
+                widget:    BadgeWidgetDescriptor {
                     columns:       2,
                     alignment:     BadgeWidgetAlignment::Center,
                     border_radius: 6
-                }
-            }
+                },
+                show_icon: false,
+                color:     None
+            },
+            avatar: None
         }
     }
 
@@ -377,4 +505,67 @@ Old profile content
         assert!(updated.contains("testuser/testrepo"));
         assert!(!updated.contains("Old content here"));
     }
+
+    fn fast_retry() -> RetryConfig {
+        RetryConfig {
+            max_attempts:     2,
+            initial_delay_ms: 1,
+            backoff_factor:   1.0
+        }
+    }
+
+    #[test]
+    fn metrics_links_follow_document_order() {
+        let document = TargetsDocument {
+            targets: vec![
+                sample_target("user1", None, TargetKind::Profile, "profile"),
+                sample_target("user1", Some("repo1"), TargetKind::OpenSource, "repo1"),
+            ]
+        };
+
+        assert_eq!(
+            metrics_links(&document),
+            [
+                format!("{METRICS_BASE_URL}/profile.svg"),
+                format!("{METRICS_BASE_URL}/repo1.svg")
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn check_metrics_links_reports_non_ok_responses() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path}
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("HEAD"))
+            .and(path("/metrics/present.svg"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        Mock::given(method("HEAD"))
+            .and(path("/metrics/missing.svg"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = Octocrab::builder().build().expect("octocrab build");
+        let urls = vec![
+            format!("{}/metrics/present.svg", server.uri()),
+            format!("{}/metrics/missing.svg", server.uri()),
+        ];
+
+        let broken = check_metrics_links(&octocrab, &urls, &fast_retry()).await;
+
+        assert_eq!(
+            broken,
+            [BrokenLink {
+                url:    urls[1].clone(),
+                reason: "HTTP 404".to_owned()
+            }]
+        );
+    }
 }
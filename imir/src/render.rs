@@ -24,6 +24,150 @@ pub struct ProfileInputs {
     pub plugin_achievements_secrets: String
 }
 
+/// Affiliation and visibility strings forwarded to the metrics plugins for a
+/// single `include_private` state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PrivacyMapping {
+    /// Affiliations used to list repositories for the profile.
+    pub repositories_affiliations:        String,
+    /// Affiliations passed to the `repositories` plugin.
+    pub plugin_repositories_affiliations: String,
+    /// Event visibility for the `activity` plugin.
+    pub plugin_activity_visibility:       String,
+    /// Repository visibility for the `code` plugin.
+    pub plugin_code_visibility:           String,
+    /// Whether the `achievements` plugin may show secret achievements.
+    pub plugin_achievements_secrets:      String
+}
+
+impl PrivacyMapping {
+    /// Mapping applied when private repositories are excluded.
+    #[must_use]
+    pub fn public_default() -> Self {
+        Self {
+            repositories_affiliations:        "owner, organization_member".to_owned(),
+            plugin_repositories_affiliations: "owner, organization_member".to_owned(),
+            plugin_activity_visibility:       "public".to_owned(),
+            plugin_code_visibility:           "public".to_owned(),
+            plugin_achievements_secrets:      "no".to_owned()
+        }
+    }
+
+    /// Mapping applied when private repositories are included.
+    #[must_use]
+    pub fn private_default() -> Self {
+        Self {
+            repositories_affiliations:        "owner, collaborator, organization_member"
+                .to_owned(),
+            plugin_repositories_affiliations: "owner, collaborator, organization_member"
+                .to_owned(),
+            plugin_activity_visibility:       "all".to_owned(),
+            plugin_code_visibility:           "all".to_owned(),
+            plugin_achievements_secrets:      "yes".to_owned()
+        }
+    }
+}
+
+/// Plugin mappings selected by the `include_private` profile input.
+///
+/// Each side can be replaced independently through the `privacy` section of
+/// the targets configuration; a replaced side must list every field.
+///
+/// # Examples
+///
+/// ```
+/// use imir::PrivacyPolicy;
+///
+/// let yaml = r#"
+/// public:
+///   repositories_affiliations: "owner, collaborator"
+///   plugin_repositories_affiliations: "owner, collaborator"
+///   plugin_activity_visibility: public
+///   plugin_code_visibility: public
+///   plugin_achievements_secrets: "no"
+/// "#;
+/// let policy: PrivacyPolicy = serde_yaml::from_str(yaml).expect("valid policy");
+/// assert_eq!(
+///     policy.public.repositories_affiliations,
+///     "owner, collaborator"
+/// );
+/// assert_eq!(policy.private, PrivacyPolicy::default().private);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PrivacyPolicy {
+    /// Mapping used when `include_private` is false.
+    #[serde(default = "PrivacyMapping::public_default")]
+    pub public:  PrivacyMapping,
+    /// Mapping used when `include_private` is true.
+    #[serde(default = "PrivacyMapping::private_default")]
+    pub private: PrivacyMapping
+}
+
+impl Default for PrivacyPolicy {
+    fn default() -> Self {
+        Self {
+            public:  PrivacyMapping::public_default(),
+            private: PrivacyMapping::private_default()
+        }
+    }
+}
+
+/// Raw profile render inputs awaiting normalization.
+///
+/// Empty optional values are treated the same as missing ones.
+///
+/// # Examples
+///
+/// ```
+/// use imir::{PrivacyPolicy, ProfileRequest, normalize_profile_inputs};
+///
+/// let policy = PrivacyPolicy::default();
+/// let request = ProfileRequest {
+///     include_private: Some("true"),
+///     ..ProfileRequest::new("octocat", &policy)
+/// };
+/// let inputs = normalize_profile_inputs(&request).expect("valid inputs");
+/// assert_eq!(inputs.include_private, "true");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ProfileRequest<'a> {
+    /// GitHub user or organization.
+    pub target_user:     &'a str,
+    /// Branch for commits.
+    pub branch_name:     Option<&'a str>,
+    /// Destination path.
+    pub target_path:     Option<&'a str>,
+    /// Temp artifact filename.
+    pub temp_artifact:   Option<&'a str>,
+    /// Time zone string.
+    pub time_zone:       Option<&'a str>,
+    /// Display name for commits.
+    pub display_name:    Option<&'a str>,
+    /// Include private repos.
+    pub include_private: Option<&'a str>,
+    /// Plugin mappings selected by `include_private`.
+    pub policy:          &'a PrivacyPolicy
+}
+
+impl<'a> ProfileRequest<'a> {
+    /// Creates a request for `target_user` with every optional input unset.
+    #[must_use]
+    pub const fn new(target_user: &'a str, policy: &'a PrivacyPolicy) -> Self {
+        Self {
+            target_user,
+            branch_name: None,
+            target_path: None,
+            temp_artifact: None,
+            time_zone: None,
+            display_name: None,
+            include_private: None,
+            policy
+        }
+    }
+}
+
 /// Normalized repository render inputs.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RepositoryInputs {
@@ -40,13 +184,7 @@ pub struct RepositoryInputs {
 ///
 /// # Arguments
 ///
-/// * `target_user` - GitHub user or organization
-/// * `branch_name` - Branch for commits (optional)
-/// * `target_path` - Destination path (optional)
-/// * `temp_artifact` - Temp artifact filename (optional)
-/// * `time_zone` - Time zone string (optional)
-/// * `display_name` - Display name for commits (optional)
-/// * `include_private` - Include private repos (optional)
+/// * `request` - Raw inputs and the privacy policy to apply
 ///
 /// # Returns
 ///
@@ -56,15 +194,18 @@ pub struct RepositoryInputs {
 ///
 /// Returns [`AppError`] when `target_user` is empty or `include_private` is
 /// invalid.
-pub fn normalize_profile_inputs(
-    target_user: &str,
-    branch_name: Option<&str>,
-    target_path: Option<&str>,
-    temp_artifact: Option<&str>,
-    time_zone: Option<&str>,
-    display_name: Option<&str>,
-    include_private: Option<&str>
-) -> Result<ProfileInputs, AppError> {
+pub fn normalize_profile_inputs(request: &ProfileRequest<'_>) -> Result<ProfileInputs, AppError> {
+    let ProfileRequest {
+        target_user,
+        branch_name,
+        target_path,
+        temp_artifact,
+        time_zone,
+        display_name,
+        include_private,
+        policy
+    } = *request;
+
     if target_user.is_empty() {
         return Err(AppError::validation("target_user must be provided"));
     }
@@ -89,30 +230,9 @@ pub fn normalize_profile_inputs(
 
     let private_normalized = include_private.unwrap_or("").to_lowercase();
 
-    let (
-        include_priv,
-        repos_affil,
-        plugin_repos_affil,
-        plugin_activity_vis,
-        plugin_code_vis,
-        plugin_achievements_sec
-    ) = match private_normalized.as_str() {
-        "" | "false" | "0" | "no" => (
-            "false",
-            "owner, organization_member",
-            "owner, organization_member",
-            "public",
-            "public",
-            "no"
-        ),
-        "true" | "1" | "yes" => (
-            "true",
-            "owner, collaborator, organization_member",
-            "owner, collaborator, organization_member",
-            "all",
-            "all",
-            "yes"
-        ),
+    let (include_priv, mapping) = match private_normalized.as_str() {
+        "" | "false" | "0" | "no" => ("false", &policy.public),
+        "true" | "1" | "yes" => ("true", &policy.private),
         _ => {
             return Err(AppError::validation(
                 "include_private must be a boolean value"
@@ -128,11 +248,11 @@ pub fn normalize_profile_inputs(
         time_zone: tz.to_string(),
         display_name: name.to_string(),
         include_private: include_priv.to_string(),
-        repositories_affiliations: repos_affil.to_string(),
-        plugin_repositories_affiliations: plugin_repos_affil.to_string(),
-        plugin_activity_visibility: plugin_activity_vis.to_string(),
-        plugin_code_visibility: plugin_code_vis.to_string(),
-        plugin_achievements_secrets: plugin_achievements_sec.to_string()
+        repositories_affiliations: mapping.repositories_affiliations.clone(),
+        plugin_repositories_affiliations: mapping.plugin_repositories_affiliations.clone(),
+        plugin_activity_visibility: mapping.plugin_activity_visibility.clone(),
+        plugin_code_visibility: mapping.plugin_code_visibility.clone(),
+        plugin_achievements_secrets: mapping.plugin_achievements_secrets.clone()
     })
 }
 
@@ -232,7 +352,8 @@ mod tests {
     #[test]
     fn normalize_profile_inputs_with_defaults() {
         let result =
-            normalize_profile_inputs("octocat", None, None, None, None, None, None).unwrap();
+            normalize_profile_inputs(&ProfileRequest::new("octocat", &PrivacyPolicy::default()))
+                .unwrap();
 
         assert_eq!(result.target_user, "octocat");
         assert_eq!(result.branch_name, "ci/metrics-refresh-profile");
@@ -245,15 +366,15 @@ mod tests {
 
     #[test]
     fn normalize_profile_inputs_with_custom_values() {
-        let result = normalize_profile_inputs(
-            "custom-user",
-            Some("custom-branch"),
-            Some("custom/path.svg"),
-            Some("custom-tmp.svg"),
-            Some("UTC"),
-            Some("custom"),
-            Some("true")
-        )
+        let result = normalize_profile_inputs(&ProfileRequest {
+            branch_name: Some("custom-branch"),
+            target_path: Some("custom/path.svg"),
+            temp_artifact: Some("custom-tmp.svg"),
+            time_zone: Some("UTC"),
+            display_name: Some("custom"),
+            include_private: Some("true"),
+            ..ProfileRequest::new("custom-user", &PrivacyPolicy::default())
+        })
         .unwrap();
 
         assert_eq!(result.target_user, "custom-user");
@@ -265,16 +386,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn default_privacy_policy_reproduces_builtin_mappings() {
+        let public = normalize_profile_inputs(&ProfileRequest {
+            include_private: Some("false"),
+            ..ProfileRequest::new("octocat", &PrivacyPolicy::default())
+        })
+        .unwrap();
+        assert_eq!(
+            public.repositories_affiliations,
+            "owner, organization_member"
+        );
+        assert_eq!(
+            public.plugin_repositories_affiliations,
+            "owner, organization_member"
+        );
+        assert_eq!(public.plugin_activity_visibility, "public");
+        assert_eq!(public.plugin_code_visibility, "public");
+        assert_eq!(public.plugin_achievements_secrets, "no");
+
+        let private = normalize_profile_inputs(&ProfileRequest {
+            include_private: Some("yes"),
+            ..ProfileRequest::new("octocat", &PrivacyPolicy::default())
+        })
+        .unwrap();
+        assert_eq!(
+            private.repositories_affiliations,
+            "owner, collaborator, organization_member"
+        );
+        assert_eq!(
+            private.plugin_repositories_affiliations,
+            "owner, collaborator, organization_member"
+        );
+        assert_eq!(private.plugin_activity_visibility, "all");
+        assert_eq!(private.plugin_code_visibility, "all");
+        assert_eq!(private.plugin_achievements_secrets, "yes");
+    }
+
+    #[test]
+    fn custom_privacy_policy_overrides_public_mapping() {
+        let policy = PrivacyPolicy {
+            public:  PrivacyMapping {
+                repositories_affiliations: "owner, collaborator".to_owned(),
+                plugin_repositories_affiliations: "owner, collaborator".to_owned(),
+                ..PrivacyMapping::public_default()
+            },
+            private: PrivacyMapping::private_default()
+        };
+
+        let result = normalize_profile_inputs(&ProfileRequest::new("octocat", &policy)).unwrap();
+
+        assert_eq!(result.include_private, "false");
+        assert_eq!(result.repositories_affiliations, "owner, collaborator");
+        assert_eq!(
+            result.plugin_repositories_affiliations,
+            "owner, collaborator"
+        );
+        assert_eq!(result.plugin_activity_visibility, "public");
+    }
+
     #[test]
     fn normalize_profile_inputs_rejects_empty_target_user() {
-        let result = normalize_profile_inputs("", None, None, None, None, None, None);
+        let result = normalize_profile_inputs(&ProfileRequest::new("", &PrivacyPolicy::default()));
         assert!(result.is_err());
     }
 
     #[test]
     fn normalize_profile_inputs_rejects_invalid_include_private() {
-        let result =
-            normalize_profile_inputs("octocat", None, None, None, None, None, Some("invalid"));
+        let result = normalize_profile_inputs(&ProfileRequest {
+            include_private: Some("invalid"),
+            ..ProfileRequest::new("octocat", &PrivacyPolicy::default())
+        });
         assert!(result.is_err());
     }
 
@@ -11,7 +11,10 @@ use indicatif::{ProgressBar, ProgressStyle};
 use masterror::AppError;
 use tracing::{debug, info};
 
-use crate::{DiscoveredRepository, TargetConfig, TargetEntry, TargetKind};
+use crate::{
+    DiscoveredRepository, ParseLimits, TargetConfig, TargetEntry, TargetKind,
+    normalizer::decode_config
+};
 
 /// Synchronizes discovered repositories with the targets configuration file.
 ///
@@ -62,8 +65,8 @@ pub fn sync_targets(
 
     pb.set_message("Parsing YAML configuration...");
     debug!("Parsing YAML configuration");
-    let mut config: TargetConfig = serde_yaml::from_str(&yaml_content)
-        .map_err(|e| AppError::validation(format!("failed to parse targets config: {e}")))?;
+    let mut config: TargetConfig = decode_config(&yaml_content, &ParseLimits::default())
+        .map_err(|e| AppError::validation(e.to_string()))?;
 
     pb.set_message(format!(
         "Building index of {} existing targets...",
@@ -73,10 +76,16 @@ pub fn sync_targets(
         "Building index of {} existing targets",
         config.targets.len()
     );
-    let existing_repos: HashSet<(String, Option<String>)> = config
+    let defaults = config.defaults.clone();
+    let existing_repos: HashSet<(Option<String>, Option<String>)> = config
         .targets
         .iter()
-        .map(|t| (t.owner.clone(), t.repository.clone()))
+        .map(|t| {
+            (
+                t.resolved_owner(defaults.as_ref()).map(str::to_owned),
+                t.repository.clone()
+            )
+        })
         .collect();
 
     let mut added_count = 0;
@@ -87,14 +96,14 @@ pub fn sync_targets(
     ));
     info!("Processing {} discovered repositories", discovered.len());
     for repo in discovered {
-        let key = (repo.owner.clone(), Some(repo.repository.clone()));
+        let key = (Some(repo.owner.clone()), Some(repo.repository.clone()));
 
         if existing_repos.contains(&key) {
             debug!("Skipping existing repository: {}", repo);
         } else {
             debug!("Adding new repository: {}", repo);
             let new_entry = TargetEntry {
-                owner:               repo.owner.clone(),
+                owner:               Some(repo.owner.clone()),
                 repository:          Some(repo.repository.clone()),
                 target_type:         TargetKind::OpenSource,
                 branch_name:         None,
@@ -105,7 +114,8 @@ pub fn sync_targets(
                 slug:                None,
                 display_name:        None,
                 include_private:     None,
-                badge:               None
+                badge:               None,
+                avatar:              None
             };
 
             config.targets.push(new_entry);
@@ -124,8 +134,8 @@ pub fn sync_targets(
             config.targets.len()
         );
         config.targets.sort_by(|a, b| {
-            a.owner
-                .cmp(&b.owner)
+            a.resolved_owner(defaults.as_ref())
+                .cmp(&b.resolved_owner(defaults.as_ref()))
                 .then_with(|| a.repository.as_deref().cmp(&b.repository.as_deref()))
         });
 
@@ -211,6 +221,28 @@ targets:
         assert_eq!(added, 0);
     }
 
+    #[test]
+    fn sync_targets_skips_duplicates_inheriting_default_owner() {
+        let temp = tempdir().expect("failed to create tempdir");
+        let config_path = temp.path().join("targets.yaml");
+        let initial_yaml = r"
+defaults:
+  owner: existing
+targets:
+  - repository: repo
+    type: open_source
+";
+        fs::write(&config_path, initial_yaml).expect("failed to write config");
+
+        let discovered = vec![DiscoveredRepository {
+            owner:      "existing".to_string(),
+            repository: "repo".to_string()
+        }];
+
+        let added = sync_targets(&config_path, &discovered).expect("sync failed");
+        assert_eq!(added, 0);
+    }
+
     #[test]
     fn sync_targets_adds_multiple_repositories() {
         let temp = tempdir().expect("failed to create tempdir");
@@ -318,6 +350,24 @@ targets:
         assert!(result.is_err(), "should fail on invalid YAML");
     }
 
+    #[test]
+    fn sync_targets_rejects_config_exceeding_parse_limits() {
+        let temp = tempdir().expect("failed to create tempdir");
+        let config_path = temp.path().join("targets.yaml");
+        let yaml = format!("targets: []\n#{}\n", "x".repeat(1024 * 1024));
+        fs::write(&config_path, yaml).expect("failed to write config");
+
+        let error = sync_targets(&config_path, &[]).expect_err("expected limit error");
+        assert!(
+            error
+                .message
+                .as_deref()
+                .is_some_and(|message| message.contains("exceeding the 1048576 byte limit")),
+            "unexpected error: {:?}",
+            error.message
+        );
+    }
+
     #[test]
     fn sync_targets_returns_error_for_missing_file() {
         let temp = tempdir().expect("failed to create tempdir");
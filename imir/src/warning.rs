@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: 2026 RAprogramm <andrey.rozanov.vl@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Collection of non-fatal diagnostics produced while executing commands.
+//!
+//! Commands report recoverable problems such as deprecated invocations through
+//! a [`WarningCollector`] rather than printing them ad hoc. The CLI inspects
+//! the collector once the command finishes and may escalate the accumulated
+//! warnings into a failure when strict mode is requested.
+
+use tracing::warn;
+
+use crate::error::Error;
+
+/// Accumulates warnings emitted while executing a single command.
+///
+/// Every recorded warning is logged immediately through [`tracing`] so the
+/// collector does not change how warnings surface to interactive users.
+///
+/// # Examples
+///
+/// ```
+/// use imir::WarningCollector;
+///
+/// let mut warnings = WarningCollector::new();
+/// assert!(warnings.ensure_empty().is_ok());
+///
+/// warnings.push("option --foo is deprecated");
+/// assert_eq!(warnings.len(), 1);
+/// assert!(warnings.ensure_empty().is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WarningCollector {
+    messages: Vec<String>
+}
+
+impl WarningCollector {
+    /// Creates an empty collector.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            messages: Vec::new()
+        }
+    }
+
+    /// Logs and records a warning message.
+    ///
+    /// # Parameters
+    ///
+    /// * `message` - Human-readable description of the warning.
+    pub fn push<M>(&mut self, message: M)
+    where
+        M: Into<String>
+    {
+        let message = message.into();
+        warn!("{message}");
+        self.messages.push(message);
+    }
+
+    /// Returns `true` when no warnings were recorded.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Returns the number of recorded warnings.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Returns the recorded warning messages in emission order.
+    #[must_use]
+    pub fn messages(&self) -> &[String] {
+        &self.messages
+    }
+
+    /// Fails when at least one warning was recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`](Error::Validation) listing every recorded
+    /// warning when the collector is not empty.
+    pub fn ensure_empty(&self) -> Result<(), Error> {
+        if self.messages.is_empty() {
+            return Ok(());
+        }
+
+        Err(Error::validation(format!(
+            "{} warning(s) treated as errors: {}",
+            self.messages.len(),
+            self.messages.join("; ")
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WarningCollector;
+    use crate::error::Error;
+
+    #[test]
+    fn new_collector_is_empty() {
+        let warnings = WarningCollector::new();
+        assert!(warnings.is_empty());
+        assert_eq!(warnings.len(), 0);
+        assert!(warnings.ensure_empty().is_ok());
+    }
+
+    #[test]
+    fn push_records_messages_in_order() {
+        let mut warnings = WarningCollector::new();
+        warnings.push("first");
+        warnings.push(String::from("second"));
+
+        assert_eq!(warnings.messages(), ["first", "second"]);
+    }
+
+    #[test]
+    fn ensure_empty_lists_recorded_warnings() {
+        let mut warnings = WarningCollector::new();
+        warnings.push("deprecated flag");
+        warnings.push("stale artifact");
+
+        match warnings.ensure_empty() {
+            Err(Error::Validation {
+                message
+            }) => {
+                assert_eq!(
+                    message,
+                    "2 warning(s) treated as errors: deprecated flag; stale artifact"
+                );
+            }
+            other => panic!("expected validation error, got {other:?}")
+        }
+    }
+}